@@ -0,0 +1,101 @@
+//! Compares `grow_no_realloc` (allocate, copy, free) with `grow_realloc`
+//! (`realloc`, then move the elements to the new back of the buffer).
+//!
+//! Run with `cargo +nightly bench --bench growth`.
+
+#![feature(test)]
+
+extern crate test;
+
+use front_vec::FrontVec;
+use test::{black_box, Bencher};
+
+/// A full `FrontVec<u8>` with a copy of `data`, ready to grow.
+fn full(data: &[u8]) -> FrontVec<u8> {
+    let mut v = FrontVec::with_capacity(data.len());
+    v.extend_front_from_slice(data);
+    v
+}
+
+/// Doubles a full buffer of `size` bytes with `grow`. Each iteration also
+/// allocates and fills a fresh buffer, which the `refill_*` benches measure on
+/// their own.
+fn bench_double(b: &mut Bencher, size: usize, grow: fn(&mut FrontVec<u8>, usize)) {
+    let data = vec![1; size];
+    b.bytes = size as u64;
+    b.iter(|| {
+        let mut v = full(&data);
+        grow(&mut v, 2 * size);
+        black_box(v)
+    });
+}
+
+macro_rules! double_benches {
+    ($($size:expr => $refill:ident, $no_realloc:ident, $realloc:ident;)*) => {
+        $(
+            #[bench]
+            fn $refill(b: &mut Bencher) {
+                let data = vec![1; $size];
+                b.bytes = $size as u64;
+                b.iter(|| black_box(full(&data)));
+            }
+
+            #[bench]
+            fn $no_realloc(b: &mut Bencher) {
+                bench_double(b, $size, FrontVec::grow_no_realloc);
+            }
+
+            #[bench]
+            fn $realloc(b: &mut Bencher) {
+                bench_double(b, $size, FrontVec::grow_realloc);
+            }
+        )*
+    };
+}
+
+double_benches! {
+    1 << 10 => refill_1k, double_1k_no_realloc, double_1k_realloc;
+    16 << 10 => refill_16k, double_16k_no_realloc, double_16k_realloc;
+    128 << 10 => refill_128k, double_128k_no_realloc, double_128k_realloc;
+    1 << 20 => refill_1m, double_1m_no_realloc, double_1m_realloc;
+    16 << 20 => refill_16m, double_16m_no_realloc, double_16m_realloc;
+}
+
+/// End to end: prepend 4M `u64`s one at a time, using whichever growth path
+/// `push_front` picks.
+#[bench]
+fn push_front_4m_u64(b: &mut Bencher) {
+    b.iter(|| {
+        let mut v = FrontVec::new();
+        for i in 0..(4u64 << 20) {
+            v.push_front(i);
+        }
+        black_box(v)
+    });
+}
+
+/// Like `push_front_4m_u64`, but doubles full buffers with `grow` itself, so
+/// that one strategy is used at every size regardless of the threshold.
+fn bench_push_front_4m_u64_with(b: &mut Bencher, grow: fn(&mut FrontVec<u64>, usize)) {
+    b.iter(|| {
+        let mut v = FrontVec::new();
+        for i in 0..(4u64 << 20) {
+            if v.len() == v.capacity() {
+                let cap = v.capacity();
+                grow(&mut v, usize::max(4, 2 * cap));
+            }
+            v.push_front(i);
+        }
+        black_box(v)
+    });
+}
+
+#[bench]
+fn push_front_4m_u64_no_realloc(b: &mut Bencher) {
+    bench_push_front_4m_u64_with(b, FrontVec::grow_no_realloc);
+}
+
+#[bench]
+fn push_front_4m_u64_realloc(b: &mut Bencher) {
+    bench_push_front_4m_u64_with(b, FrontVec::grow_realloc);
+}
@@ -0,0 +1,47 @@
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::shared_front_vec::{impl_shared_front_vec, Claims, Shared};
+
+/// A thread-safe [`SharedFrontVec`]: a persistent, immutable cons-list backed
+/// by an `Arc`-shared [`FrontVec`]-style buffer.
+///
+/// Cloning is O(1) and shares the buffer, just like sharing the tail of a
+/// cons-list. Each list is a view of the last `len` elements of the buffer,
+/// laid out as shown for [`SharedFrontVec`]. The next free slot is claimed
+/// with a compare-and-swap, so when clones on different threads push onto the
+/// same tail, exactly one of them writes in place and the others copy.
+///
+/// [`FrontVec`]: crate::FrontVec
+/// [`SharedFrontVec`]: crate::SharedFrontVec#memory-layout
+pub struct ArcFrontVec<T> {
+    shared: Arc<Shared<T, AtomicUsize>>,
+    len: usize,
+}
+
+impl Claims for AtomicUsize {
+    fn new(claimed: usize) -> Self {
+        AtomicUsize::new(claimed)
+    }
+
+    fn current(&self) -> usize {
+        self.load(Ordering::Acquire)
+    }
+
+    fn current_mut(&mut self) -> &mut usize {
+        self.get_mut()
+    }
+
+    fn try_advance(&self, len: usize) -> bool {
+        self.compare_exchange(len, len + 1, Ordering::AcqRel, Ordering::Relaxed)
+            .is_ok()
+    }
+}
+
+// SAFETY: Elements are written into the buffer by one thread and may be read
+// or dropped by any other, so `T` must be both `Send` and `Sync`. Every slot is
+// written at most once, by the thread that won the compare-and-swap for it.
+unsafe impl<T: Send + Sync> Send for Shared<T, AtomicUsize> {}
+unsafe impl<T: Send + Sync> Sync for Shared<T, AtomicUsize> {}
+
+impl_shared_front_vec!(ArcFrontVec, Arc);
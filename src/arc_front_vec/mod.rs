@@ -0,0 +1,6 @@
+mod arc_front_vec;
+
+#[cfg(test)]
+mod tests;
+
+pub use arc_front_vec::*;
@@ -0,0 +1,98 @@
+use crate::ArcFrontVec;
+use std::{
+    sync::{Arc, Barrier},
+    thread,
+};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn is_send_and_sync() {
+    assert_send_sync::<ArcFrontVec<String>>();
+}
+
+#[test]
+fn cons_list_api() {
+    let nil = ArcFrontVec::new();
+    let xs = ArcFrontVec::cons(3, &nil);
+    let xs = ArcFrontVec::cons(2, &xs);
+    let xs = ArcFrontVec::cons(1, &xs);
+    assert_eq!(xs.as_ref(), &[1, 2, 3]);
+
+    let tail = xs.tail().unwrap();
+    assert_eq!(tail.as_ref(), &[2, 3]);
+    assert!(tail.shares_buffer(&xs));
+}
+
+#[test]
+fn copy_on_write_when_slot_is_taken() {
+    let mut xs = ArcFrontVec::with_capacity(4);
+    xs.push_front(3);
+    let mut ys = xs.clone();
+
+    xs.push_front(2);
+    assert!(xs.shares_buffer(&ys));
+    ys.push_front(9);
+    assert!(!xs.shares_buffer(&ys));
+    assert_eq!(xs.as_ref(), &[2, 3]);
+    assert_eq!(ys.as_ref(), &[9, 3]);
+}
+
+#[test]
+fn concurrent_push_front_from_clones() {
+    const THREADS: usize = 8;
+    const PUSHES: usize = 1000;
+
+    for _ in 0..20 {
+        let mut base = ArcFrontVec::with_capacity(THREADS * PUSHES);
+        base.push_front(usize::MAX);
+        let barrier = Barrier::new(THREADS);
+
+        let lists: Vec<ArcFrontVec<usize>> = thread::scope(|s| {
+            let handles: Vec<_> = (0..THREADS)
+                .map(|id| {
+                    let (base, barrier) = (&base, &barrier);
+                    s.spawn(move || {
+                        let mut list = base.clone();
+                        barrier.wait();
+                        for i in 0..PUSHES {
+                            list.push_front(id * PUSHES + i);
+                        }
+                        list
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        let in_place = lists.iter().filter(|l| l.shares_buffer(&base)).count();
+        assert_eq!(in_place, 1);
+
+        for (id, list) in lists.iter().enumerate() {
+            assert_eq!(list.len(), PUSHES + 1);
+            let expected = (id * PUSHES..(id + 1) * PUSHES).rev().chain([usize::MAX]);
+            assert!(list.iter().copied().eq(expected));
+        }
+    }
+}
+
+#[test]
+fn concurrent_drops() {
+    let counter = Arc::new(());
+    {
+        let mut base = ArcFrontVec::new();
+        base.push_front(counter.clone());
+        thread::scope(|s| {
+            for _ in 0..4 {
+                let mut list = base.clone();
+                let counter = counter.clone();
+                s.spawn(move || {
+                    for _ in 0..100 {
+                        list.push_front(counter.clone());
+                    }
+                });
+            }
+        });
+    }
+    assert_eq!(Arc::strong_count(&counter), 1);
+}
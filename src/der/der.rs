@@ -0,0 +1,429 @@
+//! An ASN.1 DER encoder which builds structures from the inside out.
+//!
+//! DER puts each value's length before its contents, so appending encoders
+//! have to either measure everything twice or shuffle bytes around. Writing
+//! back to front, each constructor writes its contents first and then prepends
+//! a minimal-length header. As with [`proto`](crate::proto), the elements of a
+//! `SEQUENCE` must be prepended in *reverse* order.
+//!
+//! # Example
+//!
+//! ```
+//! # use front_vec::der::Encoder;
+//! // Name ::= SEQUENCE OF SET OF SEQUENCE { type OID, value ANY }
+//! let mut enc = Encoder::new();
+//! enc.prepend_sequence(|name| {
+//!     name.prepend_set(|rdn| {
+//!         rdn.prepend_sequence(|attr| {
+//!             attr.prepend_utf8_string("test");
+//!             attr.prepend_oid(&[2, 5, 4, 3]); // commonName
+//!         });
+//!     });
+//! });
+//! assert_eq!(enc.as_bytes(), b"\x30\x0f\x31\x0d\x30\x0b\x06\x03\x55\x04\x03\x0c\x04test");
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::{front_buf::impl_front_buf_wrapper, FrontBuf, FrontVec};
+
+/// An identifier octet (or octets): a class, whether the encoding is
+/// constructed, and a tag number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tag {
+    /// The class and constructed bits, as they appear in the first octet.
+    flags: u8,
+    number: u32,
+}
+
+impl Tag {
+    pub const BOOLEAN: Tag = Tag::universal(1, false);
+    pub const INTEGER: Tag = Tag::universal(2, false);
+    pub const BIT_STRING: Tag = Tag::universal(3, false);
+    pub const OCTET_STRING: Tag = Tag::universal(4, false);
+    pub const NULL: Tag = Tag::universal(5, false);
+    pub const OID: Tag = Tag::universal(6, false);
+    pub const UTF8_STRING: Tag = Tag::universal(12, false);
+    pub const SEQUENCE: Tag = Tag::universal(16, true);
+    pub const SET: Tag = Tag::universal(17, true);
+    pub const UTC_TIME: Tag = Tag::universal(23, false);
+    pub const GENERALIZED_TIME: Tag = Tag::universal(24, false);
+
+    const CONSTRUCTED: u8 = 0x20;
+    const CONTEXT_SPECIFIC: u8 = 0x80;
+
+    pub const fn universal(number: u32, constructed: bool) -> Tag {
+        Tag {
+            flags: if constructed { Self::CONSTRUCTED } else { 0 },
+            number,
+        }
+    }
+
+    /// A context-specific tag, written `[number]` in ASN.1.
+    pub const fn context_specific(number: u32, constructed: bool) -> Tag {
+        Tag {
+            flags: Self::CONTEXT_SPECIFIC | if constructed { Self::CONSTRUCTED } else { 0 },
+            number,
+        }
+    }
+
+    pub fn is_constructed(self) -> bool {
+        self.flags & Self::CONSTRUCTED != 0
+    }
+}
+
+/// A date and time in UTC, for [`Encoder::prepend_utc_time`] and
+/// [`Encoder::prepend_generalized_time`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Time {
+    pub year: u16,
+    /// `1..=12`.
+    pub month: u8,
+    /// `1..=31`.
+    pub day: u8,
+    /// `0..=23`.
+    pub hour: u8,
+    /// `0..=59`.
+    pub minute: u8,
+    /// `0..=59`.
+    pub second: u8,
+}
+
+/// Builds DER from back to front. See the [module docs](crate::der).
+#[derive(Debug, Clone, Default)]
+pub struct Encoder {
+    buf: FrontVec<u8>,
+}
+
+impl_front_buf_wrapper!(Encoder, encoder);
+
+impl Encoder {
+    /// Prepends the identifier octets of `tag`.
+    fn prepend_tag(&mut self, tag: Tag) {
+        if tag.number < 0x1f {
+            self.buf.prepend_u8(tag.flags | tag.number as u8);
+            return;
+        }
+
+        // High tag numbers follow a `0x1f` octet, in base 128 with the high
+        // bit set on all but the last octet.
+        let mut n = tag.number;
+        self.buf.prepend_u8(n as u8 & 0x7f);
+        n >>= 7;
+        while n > 0 {
+            self.buf.prepend_u8(n as u8 | 0x80);
+            n >>= 7;
+        }
+        self.buf.prepend_u8(tag.flags | 0x1f);
+    }
+
+    /// Prepends `len` in the shortest form DER allows.
+    fn prepend_len(&mut self, len: usize) {
+        if len < 0x80 {
+            self.buf.prepend_u8(len as u8);
+            return;
+        }
+
+        let bytes = len.to_be_bytes();
+        let skip = len.leading_zeros() as usize / 8;
+        self.buf.prepend_slice(&bytes[skip..]);
+        self.buf.prepend_u8(0x80 | (bytes.len() - skip) as u8);
+    }
+
+    /// Lets `write_contents` prepend the contents of a value, then prepends
+    /// its header. Returns whatever `write_contents` returns.
+    pub fn prepend_tlv<R>(&mut self, tag: Tag, write_contents: impl FnOnce(&mut Self) -> R) -> R {
+        let len_before = self.len();
+        let ret = write_contents(self);
+        let contents_len = self.len() - len_before;
+
+        self.prepend_len(contents_len);
+        self.prepend_tag(tag);
+        ret
+    }
+
+    /// Prepends a primitive value with the given contents.
+    pub fn prepend_primitive(&mut self, tag: Tag, contents: &[u8]) {
+        self.prepend_tlv(tag, |enc| enc.prepend_raw(contents));
+    }
+
+    pub fn prepend_boolean(&mut self, b: bool) {
+        self.prepend_primitive(Tag::BOOLEAN, &[if b { 0xff } else { 0x00 }]);
+    }
+
+    pub fn prepend_null(&mut self) {
+        self.prepend_primitive(Tag::NULL, &[]);
+    }
+
+    /// Prepends an INTEGER in the fewest two's complement octets.
+    pub fn prepend_integer(&mut self, n: i64) {
+        let bytes = n.to_be_bytes();
+        // An octet can be dropped if it and the next one's top bit are all
+        // copies of the sign bit.
+        let mut skip = 0;
+        while skip < bytes.len() - 1 {
+            let redundant = match bytes[skip] {
+                0x00 => bytes[skip + 1] & 0x80 == 0,
+                0xff => bytes[skip + 1] & 0x80 != 0,
+                _ => false,
+            };
+            if !redundant {
+                break;
+            }
+            skip += 1;
+        }
+        self.prepend_primitive(Tag::INTEGER, &bytes[skip..]);
+    }
+
+    /// Prepends a non-negative INTEGER given as big-endian octets, such as a
+    /// certificate serial number. Leading zeros are dropped, and a zero octet
+    /// is added if needed to keep the value positive.
+    pub fn prepend_unsigned_integer(&mut self, big_endian: &[u8]) {
+        let first = big_endian.iter().position(|&b| b != 0);
+        let magnitude = first.map_or(&[][..], |first| &big_endian[first..]);
+        self.prepend_tlv(Tag::INTEGER, |enc| {
+            enc.prepend_raw(magnitude);
+            if magnitude.first().is_none_or(|&b| b & 0x80 != 0) {
+                enc.buf.prepend_u8(0);
+            }
+        });
+    }
+
+    pub fn prepend_octet_string(&mut self, bytes: &[u8]) {
+        self.prepend_primitive(Tag::OCTET_STRING, bytes);
+    }
+
+    /// Prepends a BIT STRING of `bytes`, whose last `unused_bits` bits aren't
+    /// part of the value. Those bits are cleared, as DER requires.
+    ///
+    /// # Panics
+    /// Panics if `unused_bits` is 8 or more, or nonzero while `bytes` is empty.
+    pub fn prepend_bit_string(&mut self, bytes: &[u8], unused_bits: u8) {
+        assert!(
+            unused_bits < 8 && (unused_bits == 0 || !bytes.is_empty()),
+            "invalid number of unused bits: {unused_bits}"
+        );
+        self.prepend_tlv(Tag::BIT_STRING, |enc| {
+            if let Some((&last, init)) = bytes.split_last() {
+                enc.buf.prepend_u8(last & (0xff << unused_bits));
+                enc.prepend_raw(init);
+            }
+            enc.buf.prepend_u8(unused_bits);
+        });
+    }
+
+    /// Prepends an OBJECT IDENTIFIER with the given arcs, such as
+    /// `&[1, 2, 840, 113549]`.
+    ///
+    /// # Panics
+    /// Panics if there are fewer than two arcs, the first is more than 2, or
+    /// the second is 40 or more while the first is 0 or 1.
+    pub fn prepend_oid(&mut self, arcs: &[u64]) {
+        let [first, second, rest @ ..] = arcs else {
+            panic!("an OID needs at least two arcs");
+        };
+        assert!(
+            *first <= 2 && (*first == 2 || *second < 40),
+            "invalid OID arcs {first}.{second}"
+        );
+
+        self.prepend_tlv(Tag::OID, |enc| {
+            for &arc in rest.iter().rev() {
+                enc.prepend_base128(arc);
+            }
+            // The first two arcs share one subidentifier.
+            enc.prepend_base128(first * 40 + second);
+        });
+    }
+
+    /// Prepends an OID subidentifier: big-endian base 128, with the high bit
+    /// set on all but the last octet.
+    fn prepend_base128(&mut self, mut n: u64) {
+        self.buf.prepend_u8(n as u8 & 0x7f);
+        n >>= 7;
+        while n > 0 {
+            self.buf.prepend_u8(n as u8 | 0x80);
+            n >>= 7;
+        }
+    }
+
+    pub fn prepend_utf8_string(&mut self, s: &str) {
+        self.prepend_primitive(Tag::UTF8_STRING, s.as_bytes());
+    }
+
+    /// Prepends a UTCTime, `YYMMDDHHMMSSZ`.
+    ///
+    /// # Panics
+    /// Panics if the year isn't in `1950..=2049` (use a GeneralizedTime for
+    /// other years, as RFC 5280 does), or any other field is out of range.
+    pub fn prepend_utc_time(&mut self, time: Time) {
+        assert!(
+            (1950..=2049).contains(&time.year),
+            "UTCTime can't represent the year {}",
+            time.year
+        );
+        self.prepend_time(Tag::UTC_TIME, time, 2);
+    }
+
+    /// Prepends a GeneralizedTime, `YYYYMMDDHHMMSSZ`.
+    ///
+    /// # Panics
+    /// Panics if the year is more than 9999, or any other field is out of
+    /// range.
+    pub fn prepend_generalized_time(&mut self, time: Time) {
+        assert!(
+            time.year <= 9999,
+            "GeneralizedTime can't represent the year {}",
+            time.year
+        );
+        self.prepend_time(Tag::GENERALIZED_TIME, time, 4);
+    }
+
+    fn prepend_time(&mut self, tag: Tag, time: Time, year_digits: usize) {
+        let Time {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        } = time;
+        assert!(
+            (1..=12).contains(&month)
+                && (1..=31).contains(&day)
+                && hour <= 23
+                && minute <= 59
+                && second <= 59,
+            "invalid time {time:?}"
+        );
+
+        let mut text = [0; 15];
+        let mut pos = 0;
+        let mut push_digits = |n: u16, digits: u32| {
+            for i in (0..digits).rev() {
+                text[pos] = b'0' + (n / 10u16.pow(i) % 10) as u8;
+                pos += 1;
+            }
+        };
+        push_digits(year, year_digits as u32);
+        for field in [month, day, hour, minute, second] {
+            push_digits(field.into(), 2);
+        }
+        text[pos] = b'Z';
+
+        self.prepend_primitive(tag, &text[..=pos]);
+    }
+
+    /// Lets `write_elements` prepend the elements of a SEQUENCE (last element
+    /// first), then prepends its header.
+    pub fn prepend_sequence<R>(&mut self, write_elements: impl FnOnce(&mut Self) -> R) -> R {
+        self.prepend_tlv(Tag::SEQUENCE, write_elements)
+    }
+
+    /// Lets `write_elements` prepend the elements of a SET or SET OF in any
+    /// order, sorts them by their encodings as DER requires, then prepends the
+    /// header.
+    pub fn prepend_set<R>(&mut self, write_elements: impl FnOnce(&mut Self) -> R) -> R {
+        self.prepend_tlv(Tag::SET, |enc| {
+            let len_before = enc.len();
+            let ret = write_elements(enc);
+            let contents_len = enc.len() - len_before;
+            sort_elements(&mut enc.buf[..contents_len]);
+            ret
+        })
+    }
+
+    /// Lets `write_value` prepend a single value, then wraps it in a
+    /// constructed context-specific `[number]` tag, as for `[number] EXPLICIT`
+    /// fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::der::Encoder;
+    /// // version [0] EXPLICIT INTEGER, with v3 = 2
+    /// let mut enc = Encoder::new();
+    /// enc.prepend_explicit(0, |enc| enc.prepend_integer(2));
+    /// assert_eq!(enc.as_bytes(), b"\xa0\x03\x02\x01\x02");
+    /// ```
+    pub fn prepend_explicit<R>(
+        &mut self,
+        number: u32,
+        write_value: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        self.prepend_tlv(Tag::context_specific(number, true), write_value)
+    }
+
+    /// Lets `write_value` prepend a single value, then replaces its tag with a
+    /// context-specific `[number]` one (keeping whether it's constructed), as
+    /// for `[number] IMPLICIT` fields.
+    ///
+    /// # Panics
+    /// Panics if `write_value` doesn't prepend anything.
+    pub fn prepend_implicit<R>(
+        &mut self,
+        number: u32,
+        write_value: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let len_before = self.len();
+        let ret = write_value(self);
+        assert!(self.len() > len_before, "no value was prepended");
+
+        let tag_len = tag_len(&self.buf);
+        let constructed = self.buf[0] & Tag::CONSTRUCTED != 0;
+        self.buf.truncate(self.len() - tag_len);
+        self.prepend_tag(Tag::context_specific(number, constructed));
+        ret
+    }
+}
+
+impl From<Encoder> for FrontVec<u8> {
+    fn from(enc: Encoder) -> Self {
+        enc.into_inner()
+    }
+}
+
+/// The length of the identifier octets at the start of `tlv`.
+fn tag_len(tlv: &[u8]) -> usize {
+    if tlv[0] & 0x1f != 0x1f {
+        return 1;
+    }
+    // The high tag number ends with the first octet without the high bit.
+    2 + tlv[1..].iter().take_while(|&&b| b & 0x80 != 0).count()
+}
+
+/// The total length of the TLV at the start of `tlv`.
+fn tlv_len(tlv: &[u8]) -> usize {
+    let tag_len = tag_len(tlv);
+    let first = tlv[tag_len];
+    if first < 0x80 {
+        return tag_len + 1 + usize::from(first);
+    }
+
+    let len_len = usize::from(first & 0x7f);
+    let len = tlv[tag_len + 1..][..len_len]
+        .iter()
+        .fold(0, |len, &b| len << 8 | usize::from(b));
+    tag_len + 1 + len_len + len
+}
+
+/// Sorts the concatenated TLVs in `elements` by their encodings.
+fn sort_elements(elements: &mut [u8]) {
+    let mut ranges = Vec::new();
+    let mut begin = 0;
+    while begin < elements.len() {
+        let end = begin + tlv_len(&elements[begin..]);
+        ranges.push(begin..end);
+        begin = end;
+    }
+    if ranges.is_sorted_by(|a, b| elements[a.clone()] <= elements[b.clone()]) {
+        return;
+    }
+
+    ranges.sort_by(|a, b| elements[a.clone()].cmp(&elements[b.clone()]));
+    let sorted: Vec<u8> = ranges
+        .into_iter()
+        .flat_map(|range| elements[range].iter().copied())
+        .collect();
+    elements.copy_from_slice(&sorted);
+}
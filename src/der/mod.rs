@@ -0,0 +1,6 @@
+mod der;
+
+#[cfg(test)]
+mod tests;
+
+pub use der::*;
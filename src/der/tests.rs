@@ -0,0 +1,270 @@
+use super::{Encoder, Tag, Time};
+
+#[test]
+fn integers() {
+    let cases: [(i64, &[u8]); 8] = [
+        (0, &[0x02, 0x01, 0x00]),
+        (127, &[0x02, 0x01, 0x7f]),
+        (128, &[0x02, 0x02, 0x00, 0x80]),
+        (256, &[0x02, 0x02, 0x01, 0x00]),
+        (-1, &[0x02, 0x01, 0xff]),
+        (-128, &[0x02, 0x01, 0x80]),
+        (-129, &[0x02, 0x02, 0xff, 0x7f]),
+        (i64::MIN, &[0x02, 0x08, 0x80, 0, 0, 0, 0, 0, 0, 0]),
+    ];
+    for (n, expected) in cases {
+        let mut enc = Encoder::new();
+        enc.prepend_integer(n);
+        assert_eq!(enc.as_bytes(), expected, "{n}");
+    }
+}
+
+#[test]
+fn unsigned_integers() {
+    let cases: [(&[u8], &[u8]); 4] = [
+        (&[], &[0x02, 0x01, 0x00]),
+        (&[0, 0], &[0x02, 0x01, 0x00]),
+        (&[0, 0x7f], &[0x02, 0x01, 0x7f]),
+        (&[0xde, 0xad], &[0x02, 0x03, 0x00, 0xde, 0xad]),
+    ];
+    for (bytes, expected) in cases {
+        let mut enc = Encoder::new();
+        enc.prepend_unsigned_integer(bytes);
+        assert_eq!(enc.as_bytes(), expected, "{bytes:x?}");
+    }
+}
+
+#[test]
+fn simple_values() {
+    let mut enc = Encoder::new();
+    enc.prepend_utf8_string("é");
+    enc.prepend_octet_string(b"hi");
+    enc.prepend_null();
+    enc.prepend_boolean(false);
+    enc.prepend_boolean(true);
+    assert_eq!(
+        enc.as_bytes(),
+        b"\x01\x01\xff\x01\x01\x00\x05\x00\x04\x02hi\x0c\x02\xc3\xa9"
+    );
+}
+
+#[test]
+fn bit_strings() {
+    let mut enc = Encoder::new();
+    enc.prepend_bit_string(&[], 0);
+    assert_eq!(enc.as_bytes(), [0x03, 0x01, 0x00]);
+
+    let mut enc = Encoder::new();
+    enc.prepend_bit_string(&[0x12, 0xff], 4);
+    assert_eq!(enc.as_bytes(), [0x03, 0x03, 0x04, 0x12, 0xf0]);
+
+    // keyUsage with digitalSignature and keyCertSign.
+    let mut enc = Encoder::new();
+    enc.prepend_bit_string(&[0x84], 2);
+    assert_eq!(enc.as_bytes(), [0x03, 0x02, 0x02, 0x84]);
+}
+
+#[test]
+#[should_panic = "unused bits"]
+fn bit_string_unused_bits_without_bytes() {
+    Encoder::new().prepend_bit_string(&[], 1);
+}
+
+#[test]
+fn oids() {
+    let cases: [(&[u64], &[u8]); 3] = [
+        (
+            &[1, 2, 840, 113549, 1, 1, 11],
+            &[
+                0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b,
+            ],
+        ),
+        (&[2, 5, 4, 3], &[0x06, 0x03, 0x55, 0x04, 0x03]),
+        (&[2, 999, 3], &[0x06, 0x03, 0x88, 0x37, 0x03]),
+    ];
+    for (arcs, expected) in cases {
+        let mut enc = Encoder::new();
+        enc.prepend_oid(arcs);
+        assert_eq!(enc.as_bytes(), expected, "{arcs:?}");
+    }
+}
+
+#[test]
+#[should_panic = "invalid OID arcs 1.40"]
+fn oid_second_arc_too_big() {
+    Encoder::new().prepend_oid(&[1, 40]);
+}
+
+#[test]
+fn times() {
+    let time = Time {
+        year: 2019,
+        month: 12,
+        day: 16,
+        hour: 3,
+        minute: 2,
+        second: 10,
+    };
+    let mut enc = Encoder::new();
+    enc.prepend_generalized_time(time);
+    enc.prepend_utc_time(time);
+    assert_eq!(
+        enc.as_bytes(),
+        b"\x17\x0d191216030210Z\x18\x0f20191216030210Z"
+    );
+
+    let mut enc = Encoder::new();
+    enc.prepend_utc_time(Time { year: 1950, ..time });
+    assert_eq!(enc.as_bytes(), b"\x17\x0d501216030210Z");
+
+    let mut enc = Encoder::new();
+    enc.prepend_generalized_time(Time { year: 812, ..time });
+    assert_eq!(enc.as_bytes(), b"\x18\x0f08121216030210Z");
+}
+
+#[test]
+#[should_panic = "UTCTime can't represent the year 2050"]
+fn utc_time_out_of_range() {
+    Encoder::new().prepend_utc_time(Time {
+        year: 2050,
+        month: 1,
+        day: 1,
+        hour: 0,
+        minute: 0,
+        second: 0,
+    });
+}
+
+#[test]
+fn long_lengths() {
+    let mut enc = Encoder::new();
+    enc.prepend_octet_string(&[7; 200]);
+    assert_eq!(enc.as_bytes()[..3], [0x04, 0x81, 0xc8]);
+    assert_eq!(enc.len(), 203);
+
+    let mut enc = Encoder::new();
+    enc.prepend_octet_string(&[7; 300]);
+    assert_eq!(enc.as_bytes()[..4], [0x04, 0x82, 0x01, 0x2c]);
+    assert_eq!(enc.len(), 304);
+
+    let mut enc = Encoder::new();
+    enc.prepend_sequence(|e| e.prepend_octet_string(&[7; 70_000]));
+    assert_eq!(
+        enc.as_bytes()[..10],
+        [0x30, 0x83, 0x01, 0x11, 0x75, 0x04, 0x83, 0x01, 0x11, 0x70]
+    );
+}
+
+#[test]
+fn sets_are_sorted() {
+    let mut enc = Encoder::new();
+    enc.prepend_set(|e| {
+        e.prepend_integer(1);
+        e.prepend_octet_string(b"a");
+        e.prepend_integer(300);
+        e.prepend_boolean(true);
+        e.prepend_integer(2);
+    });
+    assert_eq!(
+        enc.as_bytes(),
+        [
+            0x31, 0x10, //
+            0x01, 0x01, 0xff, //
+            0x02, 0x01, 0x01, //
+            0x02, 0x01, 0x02, //
+            0x02, 0x02, 0x01, 0x2c, //
+            0x04, 0x01, b'a',
+        ]
+    );
+}
+
+#[test]
+fn context_specific_tags() {
+    // version [0] EXPLICIT INTEGER, with v3 = 2
+    let mut enc = Encoder::new();
+    enc.prepend_explicit(0, |e| e.prepend_integer(2));
+    assert_eq!(enc.as_bytes(), [0xa0, 0x03, 0x02, 0x01, 0x02]);
+
+    // GeneralName's dNSName [2] IMPLICIT IA5String
+    let mut enc = Encoder::new();
+    enc.prepend_implicit(2, |e| {
+        e.prepend_primitive(Tag::universal(22, false), b"a.b");
+    });
+    assert_eq!(enc.as_bytes(), b"\x82\x03a.b");
+
+    // Constructed values stay constructed.
+    let mut enc = Encoder::new();
+    enc.prepend_implicit(1, |e| e.prepend_sequence(|e| e.prepend_null()));
+    assert_eq!(enc.as_bytes(), [0xa1, 0x02, 0x05, 0x00]);
+
+    // High tag numbers, in and out.
+    let mut enc = Encoder::new();
+    enc.prepend_explicit(200, |e| e.prepend_null());
+    assert_eq!(enc.as_bytes(), [0xbf, 0x81, 0x48, 0x02, 0x05, 0x00]);
+
+    let mut enc = Encoder::new();
+    enc.prepend_implicit(3, |e| {
+        e.prepend_tlv(Tag::context_specific(200, false), |_| {});
+    });
+    assert_eq!(enc.as_bytes(), [0x83, 0x00]);
+}
+
+#[test]
+fn certificate_fragment() {
+    // AlgorithmIdentifier { sha256WithRSAEncryption, NULL } followed by a
+    // Validity, in one SEQUENCE.
+    let not_before = Time {
+        year: 2024,
+        month: 1,
+        day: 1,
+        hour: 0,
+        minute: 0,
+        second: 0,
+    };
+    let not_after = Time {
+        year: 2050,
+        ..not_before
+    };
+    let mut enc = Encoder::new();
+    enc.prepend_sequence(|e| {
+        e.prepend_sequence(|e| {
+            e.prepend_generalized_time(not_after);
+            e.prepend_utc_time(not_before);
+        });
+        e.prepend_sequence(|e| {
+            e.prepend_null();
+            e.prepend_oid(&[1, 2, 840, 113549, 1, 1, 11]);
+        });
+    });
+
+    let mut expected = vec![0x30, 0x31, 0x30, 0x0d, 0x06, 0x09];
+    expected.extend_from_slice(&[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b]);
+    expected.extend_from_slice(&[0x05, 0x00, 0x30, 0x20]);
+    expected.extend_from_slice(b"\x17\x0d240101000000Z");
+    expected.extend_from_slice(b"\x18\x0f20500101000000Z");
+    assert_eq!(enc.as_bytes(), expected);
+}
+
+#[test]
+fn many_elements_grow_geometrically() {
+    let mut enc = Encoder::new();
+    let mut growths = 0;
+    enc.prepend_sequence(|seq| {
+        let mut cap = seq.capacity();
+        for i in 0..10_000 {
+            seq.prepend_utf8_string("ab");
+            seq.prepend_integer(i);
+            if seq.capacity() != cap {
+                cap = seq.capacity();
+                growths += 1;
+            }
+        }
+    });
+    // 79 872 bytes of contents, starting with INTEGER 9999.
+    assert!(enc
+        .as_bytes()
+        .starts_with(b"\x30\x83\x01\x38\x00\x02\x02\x27\x0f"));
+    // Doubling from 4 bytes takes 15 growths, where growing by exactly what's
+    // prepended would take 40 000.
+    assert!(growths <= 16, "{growths} growths");
+}
@@ -0,0 +1,354 @@
+//! A schema-less FlatBuffers builder.
+//!
+//! FlatBuffers are built from the end toward the start, and objects refer to
+//! each other with offsets, which matches the way a `FrontVec` grows. As with
+//! the official builders, children (strings, vectors and tables) must be
+//! created before the table referring to them, and tables can't be nested
+//! while being built.
+//!
+//! Fields are identified by their index in the schema (`0` for the first
+//! field), and whatever is added is written: like the official builders, skip
+//! `add_field` for values equal to the schema's default.
+//!
+//! # Example
+//!
+//! ```
+//! # use front_vec::flatbuffers::Builder;
+//! // table Monster { hp: short = 100; name: string; inventory: [ubyte]; }
+//! let mut b = Builder::new();
+//! let name = b.create_string("Orc");
+//! let inventory = b.create_vector(&[0u8, 1, 2]);
+//! b.start_table();
+//! b.add_field(2, inventory);
+//! b.add_field(1, name);
+//! b.add_field(0, 300i16);
+//! let monster = b.end_table();
+//! let buf = b.finish(monster);
+//! assert_eq!(buf.len() % 4, 0);
+//! ```
+
+use alloc::vec::Vec;
+use core::{iter, mem};
+
+use crate::{front_buf::impl_front_buf_wrapper, FrontBuf, FrontVec};
+
+/// The largest buffer FlatBuffers can address with its signed offsets.
+pub const MAX_BUFFER_SIZE: usize = (1 << 31) - 1;
+
+/// The location of an object in a [`Builder`], as its distance from the end of
+/// the buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Offset(u32);
+
+impl Offset {
+    /// The distance in bytes from the start of the object to the end of the
+    /// buffer.
+    pub fn from_end(self) -> usize {
+        self.0 as usize
+    }
+}
+
+mod sealed {
+    pub trait Scalar: Copy {
+        const SIZE: usize;
+
+        fn prepend_le(self, buf: &mut crate::FrontVec<u8>);
+    }
+
+    pub trait Field {
+        /// Writes `self` as a table field, returning where it starts.
+        fn push(self, builder: &mut super::Builder) -> super::Offset;
+    }
+}
+
+/// A little-endian scalar, which can be a table field or vector element.
+///
+/// This trait is sealed: it can't be implemented outside of this crate.
+pub trait Scalar: sealed::Scalar {}
+
+/// Something which can be stored in a table field: a [`Scalar`], or an
+/// [`Offset`] to a string, vector or table.
+///
+/// This trait is sealed: it can't be implemented outside of this crate.
+pub trait Field: sealed::Field {}
+
+macro_rules! impl_scalar {
+    ($($t:ty)*) => {$(
+        impl sealed::Scalar for $t {
+            const SIZE: usize = mem::size_of::<$t>();
+
+            fn prepend_le(self, buf: &mut FrontVec<u8>) {
+                buf.prepend_slice(&self.to_le_bytes());
+            }
+        }
+
+        impl Scalar for $t {}
+    )*};
+}
+
+impl_scalar!(u8 i8 u16 i16 u32 i32 u64 i64 f32 f64);
+
+impl sealed::Scalar for bool {
+    const SIZE: usize = 1;
+
+    fn prepend_le(self, buf: &mut FrontVec<u8>) {
+        buf.prepend_u8(self.into());
+    }
+}
+
+impl Scalar for bool {}
+
+impl<T: Scalar> sealed::Field for T {
+    fn push(self, builder: &mut Builder) -> Offset {
+        builder.push_scalar(self)
+    }
+}
+
+impl<T: Scalar> Field for T {}
+
+impl sealed::Field for Offset {
+    fn push(self, builder: &mut Builder) -> Offset {
+        builder.push_offset(self)
+    }
+}
+
+impl Field for Offset {}
+
+/// Builds a FlatBuffer from back to front. See the [module docs](crate::flatbuffers).
+#[derive(Debug, Clone)]
+pub struct Builder {
+    buf: FrontVec<u8>,
+    /// The largest alignment anything was written with, which the whole
+    /// buffer is padded to on `finish`.
+    min_align: usize,
+    /// Where the table being built starts, if any.
+    table_end: Option<Offset>,
+    /// The fields of the table being built, with their indices.
+    fields: Vec<(u16, Offset)>,
+    /// Every vtable written so far, for deduplication.
+    vtables: Vec<Offset>,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            buf: FrontVec::new(),
+            min_align: 1,
+            table_end: None,
+            fields: Vec::new(),
+            vtables: Vec::new(),
+        }
+    }
+}
+
+impl_front_buf_wrapper!(Builder);
+
+impl Builder {
+    /// Where the next object will start if nothing is padded.
+    fn here(&self) -> Offset {
+        assert!(
+            self.buf.len() <= MAX_BUFFER_SIZE,
+            "FlatBuffers can't exceed {MAX_BUFFER_SIZE} bytes"
+        );
+        Offset(self.buf.len() as u32)
+    }
+
+    /// Pads so that after prepending `len` more bytes, the buffer's length
+    /// (the distance from the end) is a multiple of `align`.
+    fn align(&mut self, len: usize, align: usize) {
+        self.min_align = self.min_align.max(align);
+        let padding = self.buf.len().wrapping_add(len).wrapping_neg() & (align - 1);
+        self.buf.extend_front(iter::repeat_n(0, padding));
+    }
+
+    /// Prepends `bytes` so that they start at a multiple of `align` bytes,
+    /// such as a struct's fields, and returns where they start.
+    ///
+    /// The finished buffer's length is padded to a multiple of the largest
+    /// alignment used, so offsets from the end keep their alignment from the
+    /// start.
+    ///
+    /// # Panics
+    /// Panics if `align` isn't a power of two.
+    pub fn prepend_aligned(&mut self, bytes: &[u8], align: usize) -> Offset {
+        assert!(align.is_power_of_two(), "alignment must be a power of two");
+        self.align(bytes.len(), align);
+        self.buf.prepend_slice(bytes);
+        self.here()
+    }
+
+    fn push_scalar<T: Scalar>(&mut self, value: T) -> Offset {
+        self.align(T::SIZE, T::SIZE);
+        value.prepend_le(&mut self.buf);
+        self.here()
+    }
+
+    /// Prepends a `uoffset_t` pointing forward to `target`.
+    fn push_offset(&mut self, target: Offset) -> Offset {
+        self.align(4, 4);
+        let from = self.len() + 4;
+        assert!(
+            target.from_end() <= self.len(),
+            "offset to an unwritten object"
+        );
+        self.buf.prepend_u32_le((from - target.from_end()) as u32);
+        self.here()
+    }
+
+    #[track_caller]
+    fn assert_not_in_table(&self) {
+        assert!(
+            self.table_end.is_none(),
+            "objects can't be created while building a table"
+        );
+    }
+
+    /// Writes a string: its length, its UTF-8 bytes and a nul terminator.
+    pub fn create_string(&mut self, s: &str) -> Offset {
+        self.assert_not_in_table();
+        self.align(s.len() + 1, 4);
+        self.buf.prepend_u8(0);
+        self.buf.prepend_slice(s.as_bytes());
+        self.push_scalar(s.len() as u32)
+    }
+
+    /// Writes a vector of scalars.
+    pub fn create_vector<T: Scalar>(&mut self, items: &[T]) -> Offset {
+        self.assert_not_in_table();
+        self.align(items.len() * T::SIZE, T::SIZE.max(4));
+        for &item in items.iter().rev() {
+            item.prepend_le(&mut self.buf);
+        }
+        self.push_scalar(items.len() as u32)
+    }
+
+    /// Writes a vector of strings, vectors or tables.
+    pub fn create_vector_of_offsets(&mut self, items: &[Offset]) -> Offset {
+        self.assert_not_in_table();
+        self.align(items.len() * 4, 4);
+        for &item in items.iter().rev() {
+            self.push_offset(item);
+        }
+        self.push_scalar(items.len() as u32)
+    }
+
+    /// Starts a table. Add its fields in any order with
+    /// [`add_field`](Self::add_field), then call [`end_table`](Self::end_table).
+    ///
+    /// # Panics
+    /// Panics if a table is already being built.
+    pub fn start_table(&mut self) {
+        self.assert_not_in_table();
+        self.table_end = Some(self.here());
+        self.fields.clear();
+    }
+
+    /// Adds the field with the given index to the table being built.
+    ///
+    /// # Panics
+    /// Panics if no table is being built.
+    pub fn add_field<T: Field>(&mut self, field: u16, value: T) {
+        assert!(self.table_end.is_some(), "no table is being built");
+        let loc = value.push(self);
+        self.fields.push((field, loc));
+    }
+
+    /// Adds a struct field, given as its little-endian bytes and alignment.
+    ///
+    /// # Panics
+    /// Panics if no table is being built, or `align` isn't a power of two.
+    pub fn add_struct_field(&mut self, field: u16, bytes: &[u8], align: usize) {
+        assert!(self.table_end.is_some(), "no table is being built");
+        let loc = self.prepend_aligned(bytes, align);
+        self.fields.push((field, loc));
+    }
+
+    /// Finishes the table being built, writing its vtable unless an identical
+    /// one was already written, and returns the table's offset.
+    ///
+    /// # Panics
+    /// Panics if no table is being built, a field was added twice, or the table
+    /// is bigger than 64 KiB.
+    pub fn end_table(&mut self) -> Offset {
+        let table_end = self.table_end.take().expect("no table is being built");
+
+        // The table starts with a placeholder for the `soffset_t` to its
+        // vtable.
+        let table = self.push_scalar(0i32);
+        let table_size = table.from_end() - table_end.from_end();
+
+        // The vtable: its size, the table's size, then each field's offset from
+        // the start of the table (or 0 if absent).
+        let slots = self
+            .fields
+            .iter()
+            .map(|&(field, _)| usize::from(field) + 1)
+            .max();
+        let mut vtable = alloc::vec![0u16; 2 + slots.unwrap_or(0)];
+        let vtable_len = vtable.len() * 2;
+        assert!(
+            vtable_len <= usize::from(u16::MAX) && table_size <= usize::from(u16::MAX),
+            "table too big"
+        );
+        vtable[0] = vtable_len as u16;
+        vtable[1] = table_size as u16;
+        for &(field, loc) in &self.fields {
+            let slot = &mut vtable[2 + usize::from(field)];
+            assert!(*slot == 0, "field {field} was added twice");
+            *slot = (table.from_end() - loc.from_end()) as u16;
+        }
+        let vtable: Vec<u8> = vtable.iter().flat_map(|n| n.to_le_bytes()).collect();
+
+        let existing = self.vtables.iter().copied().find(|&vt| {
+            let start = self.len() - vt.from_end();
+            self.buf[start..].starts_with(&vtable)
+        });
+        let vtable_loc = match existing {
+            Some(vt) => vt,
+            None => {
+                // The table is 4-aligned and the vtable's length even, so the
+                // vtable is 2-aligned.
+                self.buf.prepend_slice(&vtable);
+                let vt = self.here();
+                self.vtables.push(vt);
+                vt
+            }
+        };
+
+        // The table sits at `vtable - soffset`, so a vtable written later
+        // (further from the end) gives a positive offset.
+        let soffset = vtable_loc.from_end() as i32 - table.from_end() as i32;
+        let start = self.len() - table.from_end();
+        self.buf[start..start + 4].copy_from_slice(&soffset.to_le_bytes());
+
+        self.fields.clear();
+        table
+    }
+
+    /// Prepends the offset to the `root` table, and returns the finished
+    /// buffer.
+    ///
+    /// # Panics
+    /// Panics if a table is still being built.
+    pub fn finish(self, root: Offset) -> FrontVec<u8> {
+        self.finish_with(root, None)
+    }
+
+    /// Like [`finish`](Self::finish), with a 4-byte file identifier after the
+    /// root offset.
+    pub fn finish_with_identifier(self, root: Offset, file_identifier: [u8; 4]) -> FrontVec<u8> {
+        self.finish_with(root, Some(file_identifier))
+    }
+
+    fn finish_with(mut self, root: Offset, file_identifier: Option<[u8; 4]>) -> FrontVec<u8> {
+        self.assert_not_in_table();
+        let header_len = if file_identifier.is_some() { 8 } else { 4 };
+        let min_align = self.min_align.max(4);
+        self.align(header_len, min_align);
+        if let Some(ident) = file_identifier {
+            self.buf.prepend_slice(&ident);
+        }
+        self.push_offset(root);
+        self.buf
+    }
+}
@@ -0,0 +1,8 @@
+mod flatbuffers;
+
+#[cfg(test)]
+mod tests;
+#[cfg(test)]
+mod verifier;
+
+pub use flatbuffers::*;
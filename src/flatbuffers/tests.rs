@@ -0,0 +1,367 @@
+use super::{
+    verifier::{verify, FieldDef, InvalidFlatbuffer, Table, Type},
+    Builder,
+};
+
+// table Monster { hp: short; name: string; inventory: [ubyte]; }
+const MONSTER: &[FieldDef<'_>] = &[
+    FieldDef {
+        index: 0,
+        ty: Type::Scalar(2),
+        required: false,
+    },
+    FieldDef {
+        index: 1,
+        ty: Type::String,
+        required: true,
+    },
+    FieldDef {
+        index: 2,
+        ty: Type::Vector(1),
+        required: false,
+    },
+];
+
+fn monster() -> Vec<u8> {
+    let mut b = Builder::new();
+    let name = b.create_string("Orc");
+    let inventory = b.create_vector(&[0u8, 1, 2]);
+    b.start_table();
+    b.add_field(2, inventory);
+    b.add_field(1, name);
+    b.add_field(0, 300i16);
+    let monster = b.end_table();
+    b.finish(monster).to_vec()
+}
+
+// The expected buffers were produced by the official `flatbuffers` crate
+// (25.2.10), adding fields in the same order.
+
+#[test]
+fn matches_official_builder() {
+    #[rustfmt::skip]
+    let expected = [
+        0x10, 0x00, 0x00, 0x00, // root offset
+        0x00, 0x00, // padding
+        0x0a, 0x00, 0x10, 0x00, 0x06, 0x00, 0x08, 0x00, 0x0c, 0x00, // vtable
+        0x0a, 0x00, 0x00, 0x00, // soffset to the vtable
+        0x00, 0x00, 0x2c, 0x01, // padding, hp
+        0x10, 0x00, 0x00, 0x00, // name
+        0x04, 0x00, 0x00, 0x00, // inventory
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, // inventory, padding
+        0x03, 0x00, 0x00, 0x00, b'O', b'r', b'c', 0x00, // name
+    ];
+    let buf = monster();
+    assert_eq!(buf, expected);
+
+    verify(&buf, MONSTER).unwrap();
+    let monster = Table::root(&buf);
+    assert_eq!(monster.i16(0), Some(300));
+    assert_eq!(monster.str(1), Some("Orc"));
+    assert_eq!(monster.vector_bytes(2, 1), Some(&[0, 1, 2][..]));
+    assert_eq!(monster.u8(3), None);
+}
+
+// table Item { a: uint; x: ulong; }
+const ITEM: &[FieldDef<'_>] = &[
+    FieldDef {
+        index: 0,
+        ty: Type::Scalar(4),
+        required: true,
+    },
+    FieldDef {
+        index: 1,
+        ty: Type::Scalar(8),
+        required: true,
+    },
+];
+
+// table Inventory { items: [Item]; tags: [string]; }
+const INVENTORY: &[FieldDef<'_>] = &[
+    FieldDef {
+        index: 0,
+        ty: Type::VectorOfTables(ITEM),
+        required: true,
+    },
+    FieldDef {
+        index: 1,
+        ty: Type::VectorOfStrings,
+        required: true,
+    },
+];
+
+#[test]
+fn vtables_are_deduplicated() {
+    let mut b = Builder::new();
+    let mut items = Vec::new();
+    for (a, x) in [(1u32, 10u64), (2, 20)] {
+        b.start_table();
+        b.add_field(1, x);
+        b.add_field(0, a);
+        items.push(b.end_table());
+    }
+    let tags = [b.create_string("x"), b.create_string("yz")];
+    let tags = b.create_vector_of_offsets(&tags);
+    let items = b.create_vector_of_offsets(&items);
+    b.start_table();
+    b.add_field(0, items);
+    b.add_field(1, tags);
+    let root = b.end_table();
+    let buf = b.finish(root);
+
+    #[rustfmt::skip]
+    let expected = [
+        0x0c, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0c, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, b'y', b'z', 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        b'x', 0x00, 0x00, 0x00,
+        // The second item, pointing forward to the first one's vtable.
+        0xf0, 0xff, 0xff, 0xff, 0x02, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x10, 0x00, 0x04, 0x00, 0x08, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+    assert_eq!(&buf[..], expected);
+
+    verify(&buf, INVENTORY).unwrap();
+    let inventory = Table::root(&buf);
+    let items = inventory.tables(0).unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!((items[0].u32(0), items[0].u64(1)), (Some(1), Some(10)));
+    assert_eq!((items[1].u32(0), items[1].u64(1)), (Some(2), Some(20)));
+    assert_eq!(inventory.strs(1).unwrap(), ["x", "yz"]);
+}
+
+// struct Vec3 { x: float; y: float; z: float; }
+// table Shape { visible: bool; weights: [double]; scale: double; pos: Vec3; }
+const SHAPE: &[FieldDef<'_>] = &[
+    FieldDef {
+        index: 0,
+        ty: Type::Scalar(1),
+        required: false,
+    },
+    FieldDef {
+        index: 1,
+        ty: Type::Vector(8),
+        required: false,
+    },
+    FieldDef {
+        index: 2,
+        ty: Type::Scalar(8),
+        required: false,
+    },
+    FieldDef {
+        index: 3,
+        ty: Type::Struct { size: 12, align: 4 },
+        required: false,
+    },
+];
+
+#[test]
+fn alignment_structs_and_identifier() {
+    let pos: Vec<u8> = [1.0f32, 2.0, 3.0]
+        .iter()
+        .flat_map(|f| f.to_le_bytes())
+        .collect();
+
+    let mut b = Builder::with_headroom(128);
+    let weights = b.create_vector(&[1.5f64, -2.0]);
+    b.start_table();
+    b.add_field(2, 0.25f64);
+    b.add_field(1, weights);
+    b.add_struct_field(3, &pos, 4);
+    b.add_field(0, true);
+    let shape = b.end_table();
+    let buf = b.finish_with_identifier(shape, *b"MONS");
+
+    #[rustfmt::skip]
+    let expected = [
+        0x18, 0x00, 0x00, 0x00, b'M', b'O', b'N', b'S', 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x24, 0x00, 0x07, 0x00, 0x14, 0x00, 0x18, 0x00, 0x08, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x80, 0x3f,
+        0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x40, 0x40, 0x10, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xd0, 0x3f, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x3f,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0,
+    ];
+    assert_eq!(&buf[..], expected);
+    // Padded to the largest alignment, so offsets from the end line up with
+    // offsets from the start.
+    assert_eq!(buf.len() % 8, 0);
+
+    verify(&buf, SHAPE).unwrap();
+    let shape = Table::root(&buf);
+    assert_eq!(shape.u8(0), Some(1));
+    assert_eq!(shape.f64(2), Some(0.25));
+    assert_eq!(shape.struct_bytes(3, 12), Some(&pos[..]));
+    let weights = shape.vector_bytes(1, 8).unwrap();
+    assert_eq!(&weights[8..], (-2.0f64).to_le_bytes());
+}
+
+#[test]
+fn prepend_aligned() {
+    let mut b = Builder::new();
+    b.prepend_aligned(&[1, 2, 3], 1);
+    let at = b.prepend_aligned(&[4; 5], 16);
+    assert_eq!(at.from_end() % 16, 0);
+    assert_eq!(b.len(), 16);
+
+    b.start_table();
+    let root = b.end_table();
+    let buf = b.finish(root);
+    assert_eq!(buf.len() % 16, 0);
+    // The bytes keep their alignment from the start of the buffer.
+    assert_eq!((buf.len() - at.from_end()) % 16, 0);
+    verify(&buf, &[]).unwrap();
+}
+
+#[test]
+fn verifier_rejects_corruption() {
+    let buf = monster();
+
+    let truncated = &buf[..buf.len() - 4];
+    assert!(matches!(
+        verify(truncated, MONSTER),
+        Err(InvalidFlatbuffer::RangeOutOfBounds { .. })
+    ));
+
+    let mut bad_root = buf.clone();
+    bad_root[0] = 0x11;
+    assert!(matches!(
+        verify(&bad_root, MONSTER),
+        Err(InvalidFlatbuffer::Unaligned { position: 17, .. })
+    ));
+
+    let mut bad_soffset = buf.clone();
+    bad_soffset[16..20].copy_from_slice(&1000i32.to_le_bytes());
+    assert!(matches!(
+        verify(&bad_soffset, MONSTER),
+        Err(InvalidFlatbuffer::SignedOffsetOutOfBounds { soffset: 1000, .. })
+    ));
+
+    let mut no_nul = buf.clone();
+    *no_nul.last_mut().unwrap() = b'!';
+    assert!(matches!(
+        verify(&no_nul, MONSTER),
+        Err(InvalidFlatbuffer::MissingNullTerminator { .. })
+    ));
+
+    let mut bad_utf8 = buf.clone();
+    let len = bad_utf8.len();
+    bad_utf8[len - 2] = 0xff;
+    assert!(matches!(
+        verify(&bad_utf8, MONSTER),
+        Err(InvalidFlatbuffer::Utf8Error { .. })
+    ));
+
+    let mut b = Builder::new();
+    b.start_table();
+    b.add_field(0, 1i16);
+    let root = b.end_table();
+    assert_eq!(
+        verify(&b.finish(root), MONSTER),
+        Err(InvalidFlatbuffer::MissingRequiredField { field: 1 })
+    );
+}
+
+#[test]
+fn deeply_nested_tables() {
+    // table Node { next: Node; }
+    fn node(depth: usize) -> Type<'static> {
+        let fields: &'static [FieldDef<'static>] = if depth == 0 {
+            &[]
+        } else {
+            Box::leak(Box::new([FieldDef {
+                index: 0,
+                ty: node(depth - 1),
+                required: true,
+            }]))
+        };
+        Type::Table(fields)
+    }
+
+    let build = |depth| {
+        let mut b = Builder::new();
+        b.start_table();
+        let mut next = b.end_table();
+        for _ in 0..depth {
+            b.start_table();
+            b.add_field(0, next);
+            next = b.end_table();
+        }
+        b.finish(next)
+    };
+
+    let Type::Table(root) = node(63) else {
+        unreachable!()
+    };
+    let buf = build(63);
+    verify(&buf, root).unwrap();
+    let mut table = Table::root(&buf);
+    for _ in 0..63 {
+        table = table.table(0).unwrap();
+    }
+    assert_eq!(table.field_pos(0), None);
+
+    let Type::Table(root) = node(64) else {
+        unreachable!()
+    };
+    assert_eq!(
+        verify(&build(64), root),
+        Err(InvalidFlatbuffer::DepthLimitReached)
+    );
+}
+
+#[test]
+#[should_panic = "while building a table"]
+fn no_strings_inside_tables() {
+    let mut b = Builder::new();
+    b.start_table();
+    b.create_string("nope");
+}
+
+#[test]
+#[should_panic = "no table is being built"]
+fn no_fields_outside_tables() {
+    Builder::new().add_field(0, 1u8);
+}
+
+#[test]
+#[should_panic = "field 0 was added twice"]
+fn no_duplicate_fields() {
+    let mut b = Builder::new();
+    b.start_table();
+    b.add_field(0, 1u8);
+    b.add_field(0, 2u8);
+    b.end_table();
+}
+
+#[test]
+fn many_tables_grow_geometrically() {
+    let mut b = Builder::new();
+    let mut cap = b.capacity();
+    let mut growths = 0;
+    let mut monsters = Vec::new();
+    for hp in 0..5_000i16 {
+        let name = b.create_string("Orc");
+        let inventory = b.create_vector(&[0u8, 1, 2]);
+        b.start_table();
+        b.add_field(2, inventory);
+        b.add_field(1, name);
+        b.add_field(0, hp);
+        monsters.push(b.end_table());
+        if b.capacity() != cap {
+            cap = b.capacity();
+            growths += 1;
+        }
+    }
+    let len = b.len();
+    let monsters = b.create_vector_of_offsets(&monsters);
+    let buf = b.finish(monsters);
+    assert!(buf.len() > len + 4 * 5_000);
+    // Doubling from 4 bytes takes 17 growths to reach the ~180 000 bytes,
+    // where growing by exactly what's prepended would take one per piece.
+    assert!(growths <= 18, "{growths} growths");
+}
@@ -0,0 +1,354 @@
+//! A port of the official verifier from the `flatbuffers` crate, checking
+//! buffers against a schema described at runtime, plus a minimal reader.
+//!
+//! The checks (and their order) follow `flatbuffers::Verifier`: alignment
+//! relative to the start of the buffer, bounds, signed vtable offsets, vtable
+//! sizes, nul-terminated UTF-8 strings, and depth, table count and apparent
+//! size limits.
+
+use core::str;
+
+const MAX_DEPTH: usize = 64;
+const MAX_TABLES: usize = 1_000_000;
+const MAX_APPARENT_SIZE: usize = 1 << 31;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidFlatbuffer {
+    MissingRequiredField { field: u16 },
+    Utf8Error { start: usize },
+    MissingNullTerminator { start: usize },
+    Unaligned { position: usize, align: usize },
+    RangeOutOfBounds { start: usize, end: usize },
+    SignedOffsetOutOfBounds { soffset: i32, position: usize },
+    TooManyTables,
+    ApparentSizeTooLarge,
+    DepthLimitReached,
+}
+
+type Result<T> = core::result::Result<T, InvalidFlatbuffer>;
+
+/// The type of a field or vector element.
+#[derive(Debug, Clone, Copy)]
+pub enum Type<'a> {
+    /// A scalar of the given size, which is also its alignment.
+    Scalar(usize),
+    /// An inline struct.
+    Struct {
+        size: usize,
+        align: usize,
+    },
+    String,
+    /// A vector of scalars of the given size.
+    Vector(usize),
+    VectorOfStrings,
+    Table(&'a [FieldDef<'a>]),
+    VectorOfTables(&'a [FieldDef<'a>]),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FieldDef<'a> {
+    /// The field's index in the table.
+    pub index: u16,
+    pub ty: Type<'a>,
+    pub required: bool,
+}
+
+/// The vtable slot of the field with the given index.
+fn voffset(index: u16) -> usize {
+    4 + 2 * usize::from(index)
+}
+
+/// Verifies a buffer whose root table has the given fields.
+pub fn verify(buffer: &[u8], root: &[FieldDef<'_>]) -> Result<()> {
+    let mut v = Verifier {
+        buffer,
+        depth: 0,
+        num_tables: 0,
+        apparent_size: 0,
+    };
+    v.visit_offset(0, |v, pos| v.visit_table(pos, root))
+}
+
+struct Verifier<'buf> {
+    buffer: &'buf [u8],
+    depth: usize,
+    num_tables: usize,
+    apparent_size: usize,
+}
+
+impl Verifier<'_> {
+    fn is_aligned(&self, pos: usize, align: usize) -> Result<()> {
+        if pos.is_multiple_of(align) {
+            Ok(())
+        } else {
+            Err(InvalidFlatbuffer::Unaligned {
+                position: pos,
+                align,
+            })
+        }
+    }
+
+    fn range_in_buffer(&mut self, pos: usize, size: usize) -> Result<()> {
+        let end = pos.saturating_add(size);
+        if end > self.buffer.len() {
+            return Err(InvalidFlatbuffer::RangeOutOfBounds { start: pos, end });
+        }
+        self.apparent_size += size;
+        if self.apparent_size > MAX_APPARENT_SIZE {
+            return Err(InvalidFlatbuffer::ApparentSizeTooLarge);
+        }
+        Ok(())
+    }
+
+    /// Checks that there really is a scalar of `size` bytes at `pos`.
+    fn in_buffer(&mut self, pos: usize, size: usize) -> Result<()> {
+        self.is_aligned(pos, size)?;
+        self.range_in_buffer(pos, size)
+    }
+
+    fn get_u16(&mut self, pos: usize) -> Result<u16> {
+        self.in_buffer(pos, 2)?;
+        Ok(u16::from_le_bytes([self.buffer[pos], self.buffer[pos + 1]]))
+    }
+
+    fn get_uoffset(&mut self, pos: usize) -> Result<u32> {
+        self.in_buffer(pos, 4)?;
+        Ok(u32::from_le_bytes(
+            self.buffer[pos..pos + 4].try_into().unwrap(),
+        ))
+    }
+
+    fn deref_soffset(&mut self, pos: usize) -> Result<usize> {
+        self.in_buffer(pos, 4)?;
+        let offset = i32::from_le_bytes(self.buffer[pos..pos + 4].try_into().unwrap());
+
+        // Signed offsets are subtracted.
+        let derefed = if offset > 0 {
+            pos.checked_sub(offset.unsigned_abs() as usize)
+        } else {
+            pos.checked_add(offset.unsigned_abs() as usize)
+        };
+        match derefed {
+            Some(x) if x < self.buffer.len() => Ok(x),
+            _ => Err(InvalidFlatbuffer::SignedOffsetOutOfBounds {
+                soffset: offset,
+                position: pos,
+            }),
+        }
+    }
+
+    /// Follows the `uoffset_t` at `pos`, like `ForwardsUOffset`.
+    fn visit_offset(
+        &mut self,
+        pos: usize,
+        visit: impl FnOnce(&mut Self, usize) -> Result<()>,
+    ) -> Result<()> {
+        let offset = self.get_uoffset(pos)? as usize;
+        visit(self, offset.saturating_add(pos))
+    }
+
+    fn visit_table(&mut self, table_pos: usize, fields: &[FieldDef<'_>]) -> Result<()> {
+        let vtable_pos = self.deref_soffset(table_pos)?;
+        let vtable_len = usize::from(self.get_u16(vtable_pos)?);
+        // I.e. `vtable_len` is even.
+        self.is_aligned(vtable_pos.saturating_add(vtable_len), 2)?;
+        self.range_in_buffer(vtable_pos, vtable_len)?;
+        self.num_tables += 1;
+        if self.num_tables > MAX_TABLES {
+            return Err(InvalidFlatbuffer::TooManyTables);
+        }
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(InvalidFlatbuffer::DepthLimitReached);
+        }
+
+        for field in fields {
+            let slot = voffset(field.index);
+            let mut field_pos = None;
+            if slot < vtable_len {
+                let field_offset = self.get_u16(vtable_pos.saturating_add(slot))?;
+                if field_offset > 0 {
+                    field_pos = Some(table_pos.saturating_add(usize::from(field_offset)));
+                }
+            }
+            match field_pos {
+                Some(pos) => self.visit_field(pos, field.ty)?,
+                None if field.required => {
+                    return Err(InvalidFlatbuffer::MissingRequiredField { field: field.index })
+                }
+                None => {}
+            }
+        }
+
+        self.depth -= 1;
+        Ok(())
+    }
+
+    fn visit_field(&mut self, pos: usize, ty: Type<'_>) -> Result<()> {
+        match ty {
+            Type::Scalar(size) => self.in_buffer(pos, size),
+            Type::Struct { size, align } => {
+                self.is_aligned(pos, align)?;
+                self.range_in_buffer(pos, size)
+            }
+            Type::String => self.visit_offset(pos, Self::visit_string),
+            Type::Vector(size) => self.visit_offset(pos, |v, pos| {
+                v.verify_vector_range(pos, size)?;
+                Ok(())
+            }),
+            Type::VectorOfStrings => self.visit_offset(pos, |v, pos| {
+                for elem in v.verify_vector_range(pos, 4)?.step_by(4) {
+                    v.visit_offset(elem, Self::visit_string)?;
+                }
+                Ok(())
+            }),
+            Type::Table(fields) => self.visit_offset(pos, |v, pos| v.visit_table(pos, fields)),
+            Type::VectorOfTables(fields) => self.visit_offset(pos, |v, pos| {
+                for elem in v.verify_vector_range(pos, 4)?.step_by(4) {
+                    v.visit_offset(elem, |v, pos| v.visit_table(pos, fields))?;
+                }
+                Ok(())
+            }),
+        }
+    }
+
+    /// Checks and returns the range of a vector's elements.
+    fn verify_vector_range(
+        &mut self,
+        pos: usize,
+        elem_size: usize,
+    ) -> Result<core::ops::Range<usize>> {
+        let len = self.get_uoffset(pos)? as usize;
+        let start = pos.saturating_add(4);
+        self.is_aligned(start, elem_size)?;
+        let size = len.saturating_mul(elem_size);
+        let end = start.saturating_add(size);
+        self.range_in_buffer(start, size)?;
+        Ok(start..end)
+    }
+
+    fn visit_string(&mut self, pos: usize) -> Result<()> {
+        let range = self.verify_vector_range(pos, 1)?;
+        let has_null_terminator = self.buffer.get(range.end) == Some(&0);
+        if str::from_utf8(&self.buffer[range.clone()]).is_err() {
+            return Err(InvalidFlatbuffer::Utf8Error { start: range.start });
+        }
+        if !has_null_terminator {
+            return Err(InvalidFlatbuffer::MissingNullTerminator { start: range.start });
+        }
+        Ok(())
+    }
+}
+
+/// Reads the fields of a table in a verified buffer.
+#[derive(Debug, Clone, Copy)]
+pub struct Table<'a> {
+    buffer: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Table<'a> {
+    pub fn root(buffer: &'a [u8]) -> Self {
+        Self {
+            buffer,
+            pos: read_uoffset(buffer, 0),
+        }
+    }
+
+    pub fn field_pos(&self, index: u16) -> Option<usize> {
+        let soffset = i32::from_le_bytes(self.bytes(self.pos));
+        let vtable = (self.pos as isize - soffset as isize) as usize;
+        let vtable_len = u16::from_le_bytes(self.bytes(vtable));
+        let slot = voffset(index);
+        if slot >= usize::from(vtable_len) {
+            return None;
+        }
+        match u16::from_le_bytes(self.bytes(vtable + slot)) {
+            0 => None,
+            offset => Some(self.pos + usize::from(offset)),
+        }
+    }
+
+    fn bytes<const N: usize>(&self, pos: usize) -> [u8; N] {
+        self.buffer[pos..pos + N].try_into().unwrap()
+    }
+
+    pub fn u8(&self, index: u16) -> Option<u8> {
+        self.field_pos(index).map(|pos| self.buffer[pos])
+    }
+
+    pub fn i16(&self, index: u16) -> Option<i16> {
+        self.field_pos(index)
+            .map(|pos| i16::from_le_bytes(self.bytes(pos)))
+    }
+
+    pub fn u32(&self, index: u16) -> Option<u32> {
+        self.field_pos(index)
+            .map(|pos| u32::from_le_bytes(self.bytes(pos)))
+    }
+
+    pub fn u64(&self, index: u16) -> Option<u64> {
+        self.field_pos(index)
+            .map(|pos| u64::from_le_bytes(self.bytes(pos)))
+    }
+
+    pub fn f64(&self, index: u16) -> Option<f64> {
+        self.field_pos(index)
+            .map(|pos| f64::from_le_bytes(self.bytes(pos)))
+    }
+
+    /// The raw bytes of a struct field.
+    pub fn struct_bytes(&self, index: u16, size: usize) -> Option<&'a [u8]> {
+        self.field_pos(index)
+            .map(|pos| &self.buffer[pos..pos + size])
+    }
+
+    pub fn str(&self, index: u16) -> Option<&'a str> {
+        let pos = read_uoffset(self.buffer, self.field_pos(index)?);
+        Some(read_str(self.buffer, pos))
+    }
+
+    pub fn table(&self, index: u16) -> Option<Table<'a>> {
+        let pos = read_uoffset(self.buffer, self.field_pos(index)?);
+        Some(Table {
+            buffer: self.buffer,
+            pos,
+        })
+    }
+
+    /// The raw bytes of a vector of scalars of `elem_size` bytes.
+    pub fn vector_bytes(&self, index: u16, elem_size: usize) -> Option<&'a [u8]> {
+        let pos = read_uoffset(self.buffer, self.field_pos(index)?);
+        let len = u32::from_le_bytes(self.bytes(pos)) as usize;
+        Some(&self.buffer[pos + 4..][..len * elem_size])
+    }
+
+    pub fn strs(&self, index: u16) -> Option<Vec<&'a str>> {
+        let elems = self.offsets(index)?;
+        Some(elems.map(|pos| read_str(self.buffer, pos)).collect())
+    }
+
+    pub fn tables(&self, index: u16) -> Option<Vec<Table<'a>>> {
+        let buffer = self.buffer;
+        let elems = self.offsets(index)?;
+        Some(elems.map(|pos| Table { buffer, pos }).collect())
+    }
+
+    /// Follows the offsets in a vector of offsets.
+    fn offsets(&self, index: u16) -> Option<impl Iterator<Item = usize> + 'a> {
+        let buffer = self.buffer;
+        let pos = read_uoffset(buffer, self.field_pos(index)?);
+        let len = u32::from_le_bytes(self.bytes(pos)) as usize;
+        Some((0..len).map(move |i| read_uoffset(buffer, pos + 4 + 4 * i)))
+    }
+}
+
+/// Follows the `uoffset_t` at `pos`.
+fn read_uoffset(buffer: &[u8], pos: usize) -> usize {
+    pos + u32::from_le_bytes(buffer[pos..pos + 4].try_into().unwrap()) as usize
+}
+
+fn read_str(buffer: &[u8], pos: usize) -> &str {
+    let len = u32::from_le_bytes(buffer[pos..pos + 4].try_into().unwrap()) as usize;
+    str::from_utf8(&buffer[pos + 4..][..len]).unwrap()
+}
@@ -0,0 +1,167 @@
+use core::{fmt, ops::Deref};
+
+use crate::FrontArrayVec;
+
+/// A fixed-capacity, allocation-free [`FrontString`](crate::FrontString) which
+/// stores up to `N` bytes inline.
+///
+/// # Example
+///
+/// ```
+/// # use front_vec::FrontArrayString;
+/// const GREETING: FrontArrayString<16> = {
+///     let mut s = FrontArrayString::new();
+///     s.push_str_front("world!");
+///     s.push_str_front("Hello, ");
+///     s
+/// };
+/// assert_eq!(GREETING.as_str(), "Hello, world!");
+/// ```
+pub struct FrontArrayString<const N: usize> {
+    /// Must always contain valid UTF8 sequence of bytes.
+    buf: FrontArrayVec<u8, N>,
+}
+
+impl<const N: usize> FrontArrayString<N> {
+    pub const fn new() -> Self {
+        Self {
+            buf: FrontArrayVec::new(),
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// The number of bytes that can still be prepended.
+    pub const fn remaining_capacity(&self) -> usize {
+        self.buf.remaining_capacity()
+    }
+
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: Because `self.buf` always contains valid UTF8, this is safe.
+        unsafe { core::str::from_utf8_unchecked(self.buf.as_slice()) }
+    }
+
+    /// Prepends `s`, or hands it back if it doesn't fit.
+    pub const fn try_push_str_front<'a>(&mut self, s: &'a str) -> Result<(), &'a str> {
+        // Only whole `&str`s are prepended, so the contents stay valid UTF8.
+        match self.buf.try_prepend_from_slice(s.as_bytes()) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(s),
+        }
+    }
+
+    /// # Panics
+    /// Panics if `s` doesn't fit.
+    #[track_caller]
+    pub const fn push_str_front(&mut self, s: &str) {
+        if self.try_push_str_front(s).is_err() {
+            panic!("FrontArrayString is full");
+        }
+    }
+
+    /// Prepends `ch`, or hands it back if it doesn't fit.
+    pub const fn try_push_char_front(&mut self, ch: char) -> Result<(), char> {
+        let mut buf = [0; 4];
+        match self.try_push_str_front(ch.encode_utf8(&mut buf)) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(ch),
+        }
+    }
+
+    /// # Panics
+    /// Panics if `ch` doesn't fit.
+    #[track_caller]
+    pub const fn push_char_front(&mut self, ch: char) {
+        if self.try_push_char_front(ch).is_err() {
+            panic!("FrontArrayString is full");
+        }
+    }
+
+    pub fn pop_char_front(&mut self) -> Option<char> {
+        let first_char = self.chars().next()?;
+        self.buf.truncate(self.len() - first_char.len_utf8());
+        Some(first_char)
+    }
+
+    /// Shortens the `FrontArrayString`, keeping the **last** `len` bytes and
+    /// dropping the rest.
+    /// If `len` is greater than the current length, this has no effect.
+    ///
+    /// # Panics
+    /// Panics if `new_len` does not lie on a `char` boundary.
+    pub fn truncate(&mut self, new_len: usize) {
+        let new_len = usize::min(self.len(), new_len);
+
+        if !self.is_char_boundary(self.len() - new_len) {
+            panic!("new length is not on a char boundary");
+        }
+
+        self.buf.truncate(new_len);
+    }
+}
+
+impl<const N: usize> Deref for FrontArrayString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for FrontArrayString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FrontArrayString<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.buf.as_slice()
+    }
+}
+
+impl<const N: usize, S: AsRef<str>> PartialEq<S> for FrontArrayString<N> {
+    fn eq(&self, other: &S) -> bool {
+        self.as_str() == other.as_ref()
+    }
+}
+
+impl<const N: usize> Eq for FrontArrayString<N> {}
+
+impl<const N: usize> fmt::Debug for FrontArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let slice: &str = self.as_str();
+        write!(f, "{slice:?}")
+    }
+}
+
+impl<const N: usize> fmt::Display for FrontArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let slice: &str = self.as_str();
+        write!(f, "{slice}")
+    }
+}
+
+impl<const N: usize> Default for FrontArrayString<N> {
+    fn default() -> Self {
+        FrontArrayString::new()
+    }
+}
+
+impl<const N: usize> Clone for FrontArrayString<N> {
+    fn clone(&self) -> Self {
+        Self {
+            buf: self.buf.clone(),
+        }
+    }
+}
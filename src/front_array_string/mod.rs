@@ -0,0 +1,6 @@
+mod front_array_string;
+
+#[cfg(test)]
+mod tests;
+
+pub use front_array_string::*;
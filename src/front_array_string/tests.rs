@@ -0,0 +1,51 @@
+use super::FrontArrayString;
+use assert2::assert;
+
+#[test]
+fn basic_string_ops() {
+    let mut s = FrontArrayString::<16>::new();
+    s.push_str_front("cadabra");
+    s.push_str_front("abra");
+    assert!(s == "abracadabra");
+    assert!(s.remaining_capacity() == 5);
+}
+
+#[test]
+fn rejects_what_does_not_fit() {
+    let mut s = FrontArrayString::<4>::new();
+    assert!(s.try_push_str_front("abc") == Ok(()));
+    assert!(s.try_push_str_front("xy") == Err("xy"));
+    assert!(s.try_push_char_front('ぬ') == Err('ぬ'));
+    assert!(s.try_push_char_front('z') == Ok(()));
+    assert!(s == "zabc");
+}
+
+#[test]
+fn unicode_characters() {
+    let mut s = FrontArrayString::<32>::new();
+    s.push_str_front("へと");
+    s.push_char_front('ほ');
+    assert!(s == "ほへと");
+    assert!(s.pop_char_front() == Some('ほ'));
+    s.truncate("と".len());
+    assert!(s == "と");
+}
+
+#[should_panic]
+#[test]
+fn bad_truncation() {
+    let mut s = FrontArrayString::<16>::new();
+    s.push_str_front("いろは");
+    s.truncate(1);
+}
+
+#[test]
+fn usable_in_const() {
+    const S: FrontArrayString<8> = {
+        let mut s = FrontArrayString::new();
+        s.push_char_front('!');
+        s.push_str_front("hi");
+        s
+    };
+    assert!(S.as_str() == "hi!");
+}
@@ -0,0 +1,267 @@
+use core::{
+    fmt,
+    mem::{self, MaybeUninit},
+    ops::{Deref, DerefMut},
+};
+
+/// A fixed-capacity, allocation-free [`FrontVec`](crate::FrontVec) which stores
+/// up to `N` elements inline.
+///
+/// # Memory Layout:
+/// ```ignore
+/// [?, ?, ?, ?, e1, e2, e3]
+///              ^^^^^^^^^^ initialized region
+/// ^^^^^^^^^^^ uninitialized region
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # use front_vec::FrontArrayVec;
+/// const HEADER: FrontArrayVec<u8, 4> = {
+///     let mut v = FrontArrayVec::new();
+///     v.push_front(0xBE);
+///     v.push_front(0xEF);
+///     v
+/// };
+/// assert_eq!(HEADER.as_slice(), &[0xEF, 0xBE]);
+/// ```
+pub struct FrontArrayVec<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> FrontArrayVec<T, N> {
+    pub const fn new() -> Self {
+        Self {
+            buf: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// The number of elements that can still be prepended.
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+
+    /// Prepends `val`, or hands it back if the `FrontArrayVec` is full.
+    pub const fn try_push_front(&mut self, val: T) -> Result<(), T> {
+        if self.len == N {
+            return Err(val);
+        }
+
+        self.buf[N - self.len - 1] = MaybeUninit::new(val);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// # Panics
+    /// Panics if the `FrontArrayVec` is full.
+    #[track_caller]
+    pub const fn push_front(&mut self, val: T) {
+        if self.len == N {
+            // Destructors can't run in a `const fn`, so `val` is leaked.
+            mem::forget(val);
+            panic!("FrontArrayVec is full");
+        }
+
+        self.buf[N - self.len - 1] = MaybeUninit::new(val);
+        self.len += 1;
+    }
+
+    pub const fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: The front element at `N - len` is initialized, and it is
+        // forgotten by decrementing `len` right after.
+        let val = unsafe { self.buf[N - self.len].assume_init_read() };
+        self.len -= 1;
+        Some(val)
+    }
+
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: The last `len` slots of `buf` are initialized.
+        unsafe {
+            core::slice::from_raw_parts(self.buf.as_ptr().add(N - self.len) as *const T, self.len)
+        }
+    }
+
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: The last `len` slots of `buf` are initialized.
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.buf.as_mut_ptr().add(N - self.len) as *mut T,
+                self.len,
+            )
+        }
+    }
+
+    /// Returns a mutable slice that references the uninitialized portion of the
+    /// underlying buffer.
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        &mut self.buf[..N - self.len]
+    }
+
+    /// # Safety
+    /// * `new_len` must be less than or equal to `N`.
+    /// * The elements at `old_len..new_len` must be initialized.
+    pub const unsafe fn set_len(&mut self, new_len: usize) {
+        self.len = new_len;
+    }
+
+    /// Prepends all of `items`, or none of them if they don't fit, in which
+    /// case the iterator is handed back untouched.
+    ///
+    /// If `items` yields more items than its `len()` reported, none of them
+    /// are kept either: the ones taken so far are dropped, and the rest of the
+    /// iterator is handed back.
+    pub fn try_extend_front<I>(&mut self, mut items: I) -> Result<(), I>
+    where
+        I: DoubleEndedIterator<Item = T> + ExactSizeIterator,
+    {
+        let reported = items.len();
+        if reported > self.remaining_capacity() {
+            return Err(items);
+        }
+
+        // Fill the slots in front of the initialized region from back to
+        // front, and only take them over once the iterator turned out honest.
+        let end = N - self.len;
+        let mut written = 0;
+        while let Some(item) = items.next_back() {
+            if written == reported {
+                drop(item);
+                let taken = &mut self.buf[end - written..end];
+                // SAFETY: The `written` slots right before `end` were just
+                // initialized, and `len` was never extended to cover them.
+                unsafe {
+                    core::ptr::drop_in_place(taken as *mut [MaybeUninit<T>] as *mut [T]);
+                }
+                return Err(items);
+            }
+
+            self.buf[end - written - 1] = MaybeUninit::new(item);
+            written += 1;
+        }
+
+        self.len += written;
+        Ok(())
+    }
+
+    /// Shortens the `FrontArrayVec`, keeping the **last** `len` elements and
+    /// dropping the rest.
+    /// If `len` is greater than the current length, this has no effect.
+    pub fn truncate(&mut self, len: usize) {
+        let new_len = usize::min(len, self.len);
+        let old_len = mem::replace(&mut self.len, new_len);
+        let to_drop = &mut self.buf[N - old_len..N - new_len];
+        // SAFETY: `to_drop` was part of the initialized region, and is no
+        // longer reachable now that the length has been updated.
+        unsafe {
+            core::ptr::drop_in_place(to_drop as *mut [MaybeUninit<T>] as *mut [T]);
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> FrontArrayVec<T, N> {
+    /// Prepends a copy of every element of `slice`, or returns `Err(slice)`
+    /// without modifying anything if they don't all fit.
+    pub const fn try_prepend_from_slice<'a>(&mut self, slice: &'a [T]) -> Result<(), &'a [T]> {
+        if slice.len() > self.remaining_capacity() {
+            return Err(slice);
+        }
+
+        let begin = N - self.len - slice.len();
+        let mut i = 0;
+        while i < slice.len() {
+            self.buf[begin + i] = MaybeUninit::new(slice[i]);
+            i += 1;
+        }
+        self.len += slice.len();
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for FrontArrayVec<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for FrontArrayVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> Deref for FrontArrayVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for FrontArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Drop for FrontArrayVec<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: `as_mut_slice` covers exactly the initialized elements, and
+        // they're never accessed again.
+        unsafe {
+            core::ptr::drop_in_place(self.as_mut_slice() as *mut [T]);
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for FrontArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for FrontArrayVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut new = Self::new();
+        for item in self.iter().rev() {
+            new.push_front(item.clone());
+        }
+        new
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for FrontArrayVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for FrontArrayVec<T, N> {}
+
+impl<T, const N: usize> Default for FrontArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,6 @@
+mod front_array_vec;
+
+#[cfg(test)]
+mod tests;
+
+pub use front_array_vec::*;
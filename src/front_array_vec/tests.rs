@@ -0,0 +1,118 @@
+use crate::FrontArrayVec;
+use std::rc::Rc;
+
+#[test]
+fn push_pop() {
+    let mut v = FrontArrayVec::<i32, 3>::new();
+    assert_eq!(v.try_push_front(3), Ok(()));
+    assert_eq!(v.try_push_front(2), Ok(()));
+    assert_eq!(v.try_push_front(1), Ok(()));
+    assert!(v.is_full());
+    assert_eq!(v.try_push_front(0), Err(0));
+    assert_eq!(v.as_slice(), &[1, 2, 3]);
+
+    assert_eq!(v.pop_front(), Some(1));
+    assert_eq!(v.remaining_capacity(), 1);
+    v.push_front(100);
+    assert_eq!(v.as_slice(), &[100, 2, 3]);
+}
+
+#[should_panic]
+#[test]
+fn push_when_full() {
+    let mut v = FrontArrayVec::<i32, 1>::new();
+    v.push_front(1);
+    v.push_front(2);
+}
+
+#[test]
+fn usable_in_const() {
+    const V: FrontArrayVec<u16, 8> = {
+        let mut v = FrontArrayVec::new();
+        v.push_front(2);
+        if v.try_prepend_from_slice(&[0, 1]).is_err() {
+            panic!();
+        }
+        v
+    };
+    assert_eq!(V.as_slice(), &[0, 1, 2]);
+    assert_eq!(V.len(), 3);
+}
+
+#[test]
+fn try_extend_front() {
+    let mut v = FrontArrayVec::<u8, 4>::new();
+    assert!(v.try_extend_front([3, 4].into_iter()).is_ok());
+    assert!(v.try_extend_front([0, 1, 2].into_iter()).is_err());
+    assert_eq!(v.as_slice(), &[3, 4]);
+    assert!(v.try_prepend_from_slice(&[1, 2]).is_ok());
+    assert_eq!(v.as_slice(), &[1, 2, 3, 4]);
+}
+
+/// Reports one item less than it yields.
+struct Liar(std::vec::IntoIter<Rc<()>>);
+
+impl Iterator for Liar {
+    type Item = Rc<()>;
+
+    fn next(&mut self) -> Option<Rc<()>> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.len().saturating_sub(1);
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Liar {
+    fn next_back(&mut self) -> Option<Rc<()>> {
+        self.0.next_back()
+    }
+}
+
+impl ExactSizeIterator for Liar {}
+
+#[test]
+fn try_extend_front_with_lying_len() {
+    let rc = Rc::new(());
+    let mut v = FrontArrayVec::<Rc<()>, 4>::new();
+    v.push_front(rc.clone());
+
+    let rest = v
+        .try_extend_front(Liar(vec![rc.clone(), rc.clone(), rc.clone()].into_iter()))
+        .unwrap_err();
+    // Only the original element was kept, and every item that was taken got
+    // dropped.
+    assert_eq!(v.len(), 1);
+    assert_eq!(rest.0.len(), 0);
+    assert_eq!(Rc::strong_count(&rc), 2);
+}
+
+#[test]
+fn drops_every_element() {
+    let rc = Rc::new(());
+    let mut v = FrontArrayVec::<Rc<()>, 4>::new();
+    for _ in 0..4 {
+        v.push_front(rc.clone());
+    }
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    let rejected = v.try_push_front(rc.clone()).unwrap_err();
+    drop(rejected);
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    v.truncate(1);
+    assert_eq!(Rc::strong_count(&rc), 2);
+    drop(v);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn zero_sized_elements() {
+    let mut v = FrontArrayVec::<(), 2>::new();
+    v.push_front(());
+    v.push_front(());
+    assert_eq!(v.try_push_front(()), Err(()));
+    assert_eq!(v.len(), 2);
+}
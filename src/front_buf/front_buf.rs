@@ -0,0 +1,197 @@
+use crate::FrontVec;
+
+/// The encoding of the length written by
+/// [`FrontBuf::prepend_len_prefixed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LenPrefix {
+    U8,
+    U16,
+    U16Le,
+    U32,
+    U32Le,
+    U64,
+    U64Le,
+}
+
+macro_rules! prepend_num_methods {
+    ($($ty:ty => $be:ident, $le:ident;)*) => {
+        $(
+            #[doc = concat!("Prepends a big-endian `", stringify!($ty), "`.")]
+            fn $be(&mut self, n: $ty) {
+                self.prepend_slice(&n.to_be_bytes());
+            }
+
+            #[doc = concat!("Prepends a little-endian `", stringify!($ty), "`.")]
+            fn $le(&mut self, n: $ty) {
+                self.prepend_slice(&n.to_le_bytes());
+            }
+        )*
+    };
+}
+
+/// Typed writers for building byte buffers from back to front, such as network
+/// packets where each layer prepends its header once the length of its payload
+/// is known.
+///
+/// Like the `bytes` crate, the unsuffixed methods are big-endian (network byte
+/// order) and the `_le` methods are little-endian.
+///
+/// # Example
+///
+/// ```
+/// # use front_vec::{FrontBuf, FrontVec, LenPrefix};
+/// let mut packet = FrontVec::with_headroom(64);
+/// packet.prepend_len_prefixed(LenPrefix::U16, |body| {
+///     body.prepend_slice(b"payload");
+///     body.prepend_u8(0x7);
+/// });
+/// packet.prepend_u32(0xCAFE_F00D);
+/// assert_eq!(&packet[..], b"\xCA\xFE\xF0\x0D\x00\x08\x07payload");
+/// ```
+pub trait FrontBuf {
+    /// Creates an empty buffer which can have `headroom` bytes prepended
+    /// without reallocating.
+    fn with_headroom(headroom: usize) -> Self
+    where
+        Self: Sized;
+
+    /// Copies `bytes` onto the front of the buffer.
+    fn prepend_slice(&mut self, bytes: &[u8]);
+
+    /// The number of bytes currently in the buffer.
+    fn buf_len(&self) -> usize;
+
+    fn prepend_u8(&mut self, n: u8) {
+        self.prepend_slice(&[n]);
+    }
+
+    fn prepend_i8(&mut self, n: i8) {
+        self.prepend_slice(&n.to_be_bytes());
+    }
+
+    prepend_num_methods! {
+        u16 => prepend_u16, prepend_u16_le;
+        u32 => prepend_u32, prepend_u32_le;
+        u64 => prepend_u64, prepend_u64_le;
+        u128 => prepend_u128, prepend_u128_le;
+        i16 => prepend_i16, prepend_i16_le;
+        i32 => prepend_i32, prepend_i32_le;
+        i64 => prepend_i64, prepend_i64_le;
+        i128 => prepend_i128, prepend_i128_le;
+        f32 => prepend_f32, prepend_f32_le;
+        f64 => prepend_f64, prepend_f64_le;
+    }
+
+    /// Lets `write_body` prepend a body, then prepends the body's length in
+    /// bytes encoded as `prefix`. Returns whatever `write_body` returns.
+    ///
+    /// # Panics
+    /// Panics if the body's length doesn't fit in `prefix`.
+    fn prepend_len_prefixed<R>(
+        &mut self,
+        prefix: LenPrefix,
+        write_body: impl FnOnce(&mut Self) -> R,
+    ) -> R
+    where
+        Self: Sized,
+    {
+        let len_before = self.buf_len();
+        let ret = write_body(self);
+        let body_len = self.buf_len() - len_before;
+
+        match prefix {
+            LenPrefix::U8 => self.prepend_u8(checked_len(body_len, prefix)),
+            LenPrefix::U16 => self.prepend_u16(checked_len(body_len, prefix)),
+            LenPrefix::U16Le => self.prepend_u16_le(checked_len(body_len, prefix)),
+            LenPrefix::U32 => self.prepend_u32(checked_len(body_len, prefix)),
+            LenPrefix::U32Le => self.prepend_u32_le(checked_len(body_len, prefix)),
+            LenPrefix::U64 => self.prepend_u64(checked_len(body_len, prefix)),
+            LenPrefix::U64Le => self.prepend_u64_le(checked_len(body_len, prefix)),
+        }
+
+        ret
+    }
+}
+
+#[track_caller]
+fn checked_len<N: TryFrom<usize>>(len: usize, prefix: LenPrefix) -> N {
+    N::try_from(len)
+        .unwrap_or_else(|_| panic!("body length {len} doesn't fit in a {prefix:?} length prefix"))
+}
+
+impl FrontBuf for FrontVec<u8> {
+    fn with_headroom(headroom: usize) -> Self {
+        FrontVec::with_capacity(headroom)
+    }
+
+    /// Grows geometrically when the headroom runs out, so that building a
+    /// buffer from many small pieces takes amortized O(1) per byte.
+    fn prepend_slice(&mut self, bytes: &[u8]) {
+        self.reserve_front_amortized(bytes.len());
+        self.extend_front_from_slice(bytes);
+    }
+
+    fn buf_len(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Implements the common methods of a type `$name` which builds a byte format
+/// back to front, with a field `buf: FrontVec<u8>` and a `Default` impl.
+///
+/// With `encoder`, also exposes the bytes written so far, for formats which
+/// are valid at any point rather than needing a final step.
+macro_rules! impl_front_buf_wrapper {
+    ($name:ident) => {
+        impl $name {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #[doc = concat!(
+                "Creates an empty `", stringify!($name), "` which can take `headroom` bytes ",
+                "without reallocating."
+            )]
+            pub fn with_headroom(headroom: usize) -> Self {
+                let mut this = Self::default();
+                this.buf.reserve_front(headroom);
+                this
+            }
+
+            /// The number of bytes written so far.
+            pub fn len(&self) -> usize {
+                self.buf.len()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.buf.is_empty()
+            }
+
+            /// The number of bytes which fit without reallocating, including
+            /// those already written.
+            pub fn capacity(&self) -> usize {
+                self.buf.capacity()
+            }
+        }
+    };
+    ($name:ident, encoder) => {
+        $crate::front_buf::impl_front_buf_wrapper!($name);
+
+        impl $name {
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.buf
+            }
+
+            pub fn into_inner(self) -> $crate::FrontVec<u8> {
+                self.buf
+            }
+
+            /// Prepends already-encoded bytes as is.
+            pub fn prepend_raw(&mut self, bytes: &[u8]) {
+                $crate::FrontBuf::prepend_slice(&mut self.buf, bytes);
+            }
+        }
+    };
+}
+
+pub(crate) use impl_front_buf_wrapper;
@@ -0,0 +1,7 @@
+mod front_buf;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use self::front_buf::impl_front_buf_wrapper;
+pub use front_buf::*;
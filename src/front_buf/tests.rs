@@ -0,0 +1,87 @@
+use super::{FrontBuf, LenPrefix};
+use crate::FrontVec;
+
+#[test]
+fn integers_both_endians() {
+    let mut buf = FrontVec::new();
+    buf.prepend_u16_le(0x0102);
+    buf.prepend_u16(0x0102);
+    buf.prepend_i32(-2);
+    buf.prepend_i8(-1);
+    buf.prepend_u8(0xAB);
+    assert_eq!(
+        buf.as_ref(),
+        &[0xAB, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0x01, 0x02, 0x02, 0x01]
+    );
+}
+
+#[test]
+fn wide_integers() {
+    let mut buf = FrontVec::new();
+    buf.prepend_u64_le(1);
+    buf.prepend_i64(-1);
+    buf.prepend_u128(1);
+    let mut expected = vec![0; 15];
+    expected.push(1);
+    expected.extend([0xFF; 8]);
+    expected.extend([1, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(buf.as_ref(), &expected[..]);
+}
+
+#[test]
+fn floats() {
+    let mut buf = FrontVec::new();
+    buf.prepend_f32_le(1.5);
+    buf.prepend_f64(-2.0);
+    let mut expected = (-2.0f64).to_be_bytes().to_vec();
+    expected.extend(1.5f32.to_le_bytes());
+    assert_eq!(buf.as_ref(), &expected[..]);
+}
+
+#[test]
+fn nested_len_prefixes_fill_one_allocation() {
+    let mut buf = FrontVec::with_headroom(32);
+    let ret = buf.prepend_len_prefixed(LenPrefix::U32Le, |ip| {
+        ip.prepend_len_prefixed(LenPrefix::U16, |udp| {
+            udp.prepend_slice(b"hello");
+        });
+        ip.prepend_u8(17);
+        "done"
+    });
+
+    assert_eq!(ret, "done");
+    assert_eq!(buf.capacity(), 32);
+    assert_eq!(buf.as_ref(), b"\x08\x00\x00\x00\x11\x00\x05hello");
+}
+
+#[test]
+fn empty_body() {
+    let mut buf = FrontVec::new();
+    buf.prepend_len_prefixed(LenPrefix::U64, |_| {});
+    assert_eq!(buf.as_ref(), &[0; 8]);
+}
+
+#[should_panic]
+#[test]
+fn body_too_long_for_prefix() {
+    let mut buf = FrontVec::new();
+    buf.prepend_len_prefixed(LenPrefix::U8, |body| body.prepend_slice(&[0; 256]));
+}
+
+#[test]
+fn small_prepends_grow_geometrically() {
+    let mut buf = FrontVec::new();
+    let mut cap = buf.capacity();
+    let mut growths = 0;
+    for i in 0..10_000u32 {
+        buf.prepend_u8(i as u8);
+        buf.prepend_u16_le(i as u16);
+        if buf.capacity() != cap {
+            cap = buf.capacity();
+            growths += 1;
+        }
+    }
+    assert_eq!(buf.len(), 30_000);
+    // Doubling from 4 bytes takes 13 growths to reach 30 000.
+    assert!(growths <= 14, "{growths} growths");
+}
@@ -0,0 +1,118 @@
+use std::{
+    ffi::{OsStr, OsString},
+    fmt,
+    ops::Deref,
+    os::unix::ffi::{OsStrExt, OsStringExt},
+};
+
+use crate::FrontVec;
+
+/// An efficiently-prependable `OsString`.
+///
+/// Only available on Unix, where an `OsStr` is an arbitrary sequence of bytes,
+/// so concatenating two of them never produces an invalid one.
+pub struct FrontOsString {
+    buf: FrontVec<u8>,
+}
+
+impl FrontOsString {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: FrontVec::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Ensures capacity has at least `additional` more bytes of capacity.
+    ///
+    /// Returns `true` if a reallocation happened, `false` otherwise.
+    pub fn reserve_front(&mut self, additional: usize) -> bool {
+        self.buf.reserve_front(additional)
+    }
+
+    pub fn push_os_str_front<S: AsRef<OsStr>>(&mut self, s: S) {
+        let bytes = s.as_ref().as_bytes();
+        self.buf.extend_front_from_slice(bytes);
+    }
+
+    pub fn as_os_str(&self) -> &OsStr {
+        OsStr::from_bytes(self.buf.as_ref())
+    }
+
+    /// Converts into an `OsString` without copying the contents into a new
+    /// allocation.
+    pub fn into_os_string(self) -> OsString {
+        OsString::from_vec(self.buf.into())
+    }
+}
+
+impl From<&OsStr> for FrontOsString {
+    fn from(s: &OsStr) -> Self {
+        let mut fs = FrontOsString::new();
+        fs.push_os_str_front(s);
+        fs
+    }
+}
+
+impl From<OsString> for FrontOsString {
+    fn from(s: OsString) -> Self {
+        Self {
+            buf: s.into_vec().into(),
+        }
+    }
+}
+
+impl From<FrontOsString> for OsString {
+    fn from(s: FrontOsString) -> Self {
+        s.into_os_string()
+    }
+}
+
+impl Deref for FrontOsString {
+    type Target = OsStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_os_str()
+    }
+}
+
+impl AsRef<OsStr> for FrontOsString {
+    fn as_ref(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl<S: AsRef<OsStr>> PartialEq<S> for FrontOsString {
+    fn eq(&self, other: &S) -> bool {
+        self.as_os_str() == other.as_ref()
+    }
+}
+
+impl Eq for FrontOsString {}
+
+impl fmt::Debug for FrontOsString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_os_str().fmt(f)
+    }
+}
+
+impl Default for FrontOsString {
+    fn default() -> Self {
+        FrontOsString::new()
+    }
+}
+
+impl Clone for FrontOsString {
+    fn clone(&self) -> Self {
+        Self {
+            buf: self.buf.clone(),
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    ffi::{OsStr, OsString},
+    fmt,
+    ops::Deref,
+    os::unix::ffi::OsStrExt,
+    path::{Path, PathBuf},
+};
+
+use super::FrontOsString;
+
+/// An efficiently-prependable `PathBuf`, useful when path components are
+/// discovered from the leaf upward.
+///
+/// # Example
+///
+/// ```
+/// # use front_vec::FrontPathBuf;
+/// # use std::path::Path;
+/// let mut p = FrontPathBuf::new();
+/// p.push_component_front("main.rs");
+/// p.push_component_front("src");
+/// p.push_component_front("/");
+/// assert_eq!(p.as_path(), Path::new("/src/main.rs"));
+/// ```
+pub struct FrontPathBuf {
+    inner: FrontOsString,
+}
+
+impl FrontPathBuf {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: FrontOsString::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Ensures capacity has at least `additional` more bytes of capacity.
+    ///
+    /// Returns `true` if a reallocation happened, `false` otherwise.
+    pub fn reserve_front(&mut self, additional: usize) -> bool {
+        self.inner.reserve_front(additional)
+    }
+
+    /// Prepends `component` so that the result equals
+    /// `Path::new(component).join(old_self)`.
+    ///
+    /// A separator is inserted between `component` and the existing path unless
+    /// either is empty or `component` already ends in one. If the existing path
+    /// is absolute it already starts at the root, so nothing is prepended.
+    pub fn push_component_front<P: AsRef<Path>>(&mut self, component: P) {
+        let component = component.as_ref().as_os_str();
+
+        if self.has_root() || component.is_empty() {
+            return;
+        }
+
+        let needs_sep = !self.inner.is_empty() && !component.as_bytes().ends_with(b"/");
+
+        self.inner
+            .reserve_front(component.len() + usize::from(needs_sep));
+        if needs_sep {
+            self.inner.push_os_str_front("/");
+        }
+        self.inner.push_os_str_front(component);
+    }
+
+    pub fn as_path(&self) -> &Path {
+        Path::new(self.inner.as_os_str())
+    }
+
+    pub fn as_os_str(&self) -> &OsStr {
+        self.inner.as_os_str()
+    }
+
+    /// Converts into a `PathBuf` without copying the contents into a new
+    /// allocation.
+    pub fn into_path_buf(self) -> PathBuf {
+        PathBuf::from(self.inner.into_os_string())
+    }
+
+    pub fn into_front_os_string(self) -> FrontOsString {
+        self.inner
+    }
+}
+
+impl From<&Path> for FrontPathBuf {
+    fn from(p: &Path) -> Self {
+        Self {
+            inner: p.as_os_str().into(),
+        }
+    }
+}
+
+impl From<PathBuf> for FrontPathBuf {
+    fn from(p: PathBuf) -> Self {
+        Self {
+            inner: p.into_os_string().into(),
+        }
+    }
+}
+
+impl From<FrontPathBuf> for PathBuf {
+    fn from(p: FrontPathBuf) -> Self {
+        p.into_path_buf()
+    }
+}
+
+impl From<FrontPathBuf> for OsString {
+    fn from(p: FrontPathBuf) -> Self {
+        p.inner.into_os_string()
+    }
+}
+
+impl Deref for FrontPathBuf {
+    type Target = Path;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_path()
+    }
+}
+
+impl AsRef<Path> for FrontPathBuf {
+    fn as_ref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl AsRef<OsStr> for FrontPathBuf {
+    fn as_ref(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl<P: AsRef<Path>> PartialEq<P> for FrontPathBuf {
+    fn eq(&self, other: &P) -> bool {
+        self.as_path() == other.as_ref()
+    }
+}
+
+impl Eq for FrontPathBuf {}
+
+impl fmt::Debug for FrontPathBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_path().fmt(f)
+    }
+}
+
+impl Default for FrontPathBuf {
+    fn default() -> Self {
+        FrontPathBuf::new()
+    }
+}
+
+impl Clone for FrontPathBuf {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
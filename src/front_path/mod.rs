@@ -0,0 +1,8 @@
+mod front_os_string;
+mod front_path_buf;
+
+#[cfg(test)]
+mod tests;
+
+pub use front_os_string::*;
+pub use front_path_buf::*;
@@ -0,0 +1,95 @@
+use super::{FrontOsString, FrontPathBuf};
+use assert2::assert;
+use std::{
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+};
+
+#[test]
+fn os_string_push_front() {
+    let mut s = FrontOsString::from(OsStr::new("world"));
+    s.push_os_str_front(", ");
+    s.push_os_str_front("hello");
+    assert!(s == OsStr::new("hello, world"));
+}
+
+#[test]
+fn os_string_into_os_string() {
+    let mut s = FrontOsString::with_capacity(16);
+    s.push_os_str_front("bar");
+    s.push_os_str_front("foo");
+    let os: OsString = s.into();
+    assert!(os == "foobar");
+    assert!(os.capacity() == 16);
+}
+
+#[test]
+fn leaf_to_root() {
+    let mut p = FrontPathBuf::new();
+    p.push_component_front("c.txt");
+    p.push_component_front("b");
+    p.push_component_front("a");
+    assert!(p == Path::new("a/b/c.txt"));
+
+    p.push_component_front("/");
+    assert!(p == Path::new("/a/b/c.txt"));
+    assert!(p.is_absolute());
+}
+
+#[test]
+fn component_with_trailing_separator() {
+    let mut p = FrontPathBuf::from(Path::new("b"));
+    p.push_component_front("/a/");
+    assert!(p.as_os_str() == "/a/b");
+}
+
+#[test]
+fn multi_component_prefix() {
+    let mut p = FrontPathBuf::from(PathBuf::from("c"));
+    p.push_component_front("/a/b");
+    assert!(p.as_os_str() == "/a/b/c");
+}
+
+#[test]
+fn nothing_goes_in_front_of_the_root() {
+    let mut p = FrontPathBuf::from(Path::new("/usr/lib"));
+    p.push_component_front("home");
+    assert!(p.as_os_str() == "/usr/lib");
+}
+
+#[test]
+fn empty_components() {
+    let mut p = FrontPathBuf::new();
+    p.push_component_front("");
+    assert!(p.as_os_str() == "");
+    p.push_component_front("b");
+    p.push_component_front("");
+    assert!(p.as_os_str() == "b");
+    p.push_component_front("a");
+    assert!(p.as_os_str() == "a/b");
+}
+
+#[test]
+fn matches_join() {
+    for (component, rest) in [
+        ("a", "b"),
+        ("a/", "b"),
+        ("/", "b"),
+        ("a", "/b"),
+        ("/x/y", "z/w"),
+    ] {
+        let mut p = FrontPathBuf::from(Path::new(rest));
+        p.push_component_front(component);
+        assert!(p == Path::new(component).join(rest));
+    }
+}
+
+#[test]
+fn into_path_buf_keeps_allocation() {
+    let mut p = FrontPathBuf::with_capacity(32);
+    p.push_component_front("file");
+    p.push_component_front("dir");
+    let path: PathBuf = p.into();
+    assert!(path == Path::new("dir/file"));
+    assert!(path.capacity() == 32);
+}
@@ -0,0 +1,161 @@
+use core::{fmt, ops::Deref};
+
+use crate::{FrontSmallVec, FrontString};
+
+/// A [`FrontString`] that stores up to `N` bytes inline before spilling onto
+/// the heap.
+pub struct FrontSmallString<const N: usize> {
+    /// Must always contain valid UTF8 sequence of bytes.
+    buf: FrontSmallVec<u8, N>,
+}
+
+impl<const N: usize> FrontSmallString<N> {
+    pub fn new() -> Self {
+        Self {
+            buf: FrontSmallVec::new(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: FrontSmallVec::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Returns `true` if the bytes have been moved onto the heap.
+    pub fn spilled(&self) -> bool {
+        self.buf.spilled()
+    }
+
+    /// Ensures capacity has at least `additional` more bytes of capacity.
+    ///
+    /// Returns `true` if the bytes were moved to a new buffer, `false`
+    /// otherwise.
+    pub fn reserve_front(&mut self, additional: usize) -> bool {
+        self.buf.reserve_front(additional)
+    }
+
+    pub fn push_char_front(&mut self, ch: char) {
+        let mut buf = [0; 4];
+        self.push_str_front(ch.encode_utf8(&mut buf));
+    }
+
+    pub fn pop_char_front(&mut self) -> Option<char> {
+        let first_char = self.chars().next()?;
+        self.buf.truncate(self.len() - first_char.len_utf8());
+        Some(first_char)
+    }
+
+    pub fn push_str_front<S: AsRef<str>>(&mut self, s: S) {
+        let bytes = s.as_ref().as_bytes();
+        self.reserve_front(bytes.len());
+
+        let spare = self.buf.spare_capacity_mut();
+        let begin = spare.len() - bytes.len();
+        for (slot, &byte) in spare[begin..].iter_mut().zip(bytes) {
+            slot.write(byte);
+        }
+
+        // SAFETY:
+        // 1. The bytes directly in front of the initialized region were just
+        //    written.
+        // 2. They come from a `&str`, so the contents stay valid UTF8.
+        unsafe {
+            self.buf.set_len(self.len() + bytes.len());
+        }
+    }
+
+    /// Shortens the `FrontSmallString`, keeping the **last** `len` bytes and
+    /// dropping the rest.
+    /// If `len` is greater than the current length, this has no effect.
+    ///
+    /// # Panics
+    /// Panics if `new_len` does not lie on a `char` boundary.
+    pub fn truncate(&mut self, new_len: usize) {
+        let new_len = usize::min(self.len(), new_len);
+
+        if !self.is_char_boundary(self.len() - new_len) {
+            panic!("new length is not on a char boundary");
+        }
+
+        self.buf.truncate(new_len);
+    }
+
+    /// Converts into a [`FrontString`], allocating only if the bytes are
+    /// currently stored inline.
+    pub fn into_front_string(self) -> FrontString {
+        let v = self.buf.into_front_vec();
+        // SAFETY: `self.buf` always contains valid UTF8.
+        unsafe { FrontString::from_utf8_unchecked(v) }
+    }
+}
+
+impl<const N: usize> From<&str> for FrontSmallString<N> {
+    fn from(s: &str) -> Self {
+        let mut fs = FrontSmallString::with_capacity(s.len());
+        fs.push_str_front(s);
+        fs
+    }
+}
+
+impl<const N: usize> Deref for FrontSmallString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        let byte_slice = self.buf.as_ref();
+        // SAFETY: Because `self.buf` always contains valid UTF8, this is safe.
+        unsafe { core::str::from_utf8_unchecked(byte_slice) }
+    }
+}
+
+impl<const N: usize> AsRef<str> for FrontSmallString<N> {
+    fn as_ref(&self) -> &str {
+        self.deref()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FrontSmallString<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.buf.as_ref()
+    }
+}
+
+impl<const N: usize, S: AsRef<str>> PartialEq<S> for FrontSmallString<N> {
+    fn eq(&self, other: &S) -> bool {
+        <Self as AsRef<str>>::as_ref(self) == other.as_ref()
+    }
+}
+
+impl<const N: usize> Eq for FrontSmallString<N> {}
+
+impl<const N: usize> fmt::Debug for FrontSmallString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let slice: &str = self.as_ref();
+        write!(f, "{slice:?}")
+    }
+}
+
+impl<const N: usize> fmt::Display for FrontSmallString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let slice: &str = self.as_ref();
+        write!(f, "{slice}")
+    }
+}
+
+impl<const N: usize> Default for FrontSmallString<N> {
+    fn default() -> Self {
+        FrontSmallString::new()
+    }
+}
+
+impl<const N: usize> Clone for FrontSmallString<N> {
+    fn clone(&self) -> Self {
+        Self {
+            buf: self.buf.clone(),
+        }
+    }
+}
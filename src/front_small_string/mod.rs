@@ -0,0 +1,6 @@
+mod front_small_string;
+
+#[cfg(test)]
+mod tests;
+
+pub use front_small_string::*;
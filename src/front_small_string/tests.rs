@@ -0,0 +1,59 @@
+use super::FrontSmallString;
+use assert2::assert;
+
+#[test]
+fn basic_string_ops() {
+    let mut s = FrontSmallString::<16>::new();
+    s.push_str_front("cadabra");
+    s.push_str_front("abra");
+    assert!(s == "abracadabra");
+    assert!(!s.spilled());
+}
+
+#[test]
+fn spills_when_full() {
+    let mut s = FrontSmallString::<8>::from("cadabra");
+    s.push_str_front("abra");
+    assert!(s.spilled());
+    assert!(s == "abracadabra");
+}
+
+#[test]
+fn unicode_characters() {
+    let mut s = FrontSmallString::<4>::from("へ");
+    s.push_char_front('ほ');
+    assert!(s.spilled());
+    s.push_char_front('に');
+    assert!(s == "にほへ");
+
+    assert!(s.pop_char_front() == Some('に'));
+    assert!(s.pop_char_front() == Some('ほ'));
+    assert!(s == "へ");
+}
+
+#[test]
+fn truncation() {
+    let mut s = FrontSmallString::<64>::from("いろはにほへと");
+    s.truncate("ほへと".len());
+    assert!(s == "ほへと");
+}
+
+#[should_panic]
+#[test]
+fn bad_truncation() {
+    let mut s = FrontSmallString::<64>::from("いろは");
+    s.truncate(1);
+}
+
+#[test]
+fn into_front_string() {
+    let s = FrontSmallString::<16>::from("abc");
+    assert!(s.into_front_string() == "abc");
+}
+
+#[test]
+fn fmt() {
+    let s = FrontSmallString::<16>::from("asdf");
+    assert!(format!("{s}") == "asdf");
+    assert!(format!("{s:?}") == "\"asdf\"");
+}
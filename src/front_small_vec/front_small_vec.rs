@@ -0,0 +1,337 @@
+use core::{
+    fmt,
+    mem::{self, MaybeUninit},
+    ops::{Deref, DerefMut},
+};
+
+use crate::FrontVec;
+
+/// A [`FrontVec`] that stores up to `N` elements inline before spilling onto
+/// the heap.
+///
+/// # Memory Layout:
+/// Like a `FrontVec`, the inline elements are kept at the *back* of the inline
+/// buffer so that `push_front` never has to shift anything.
+/// ```ignore
+/// [?, ?, ?, ?, e1, e2, e3]
+///              ^^^^^^^^^^ initialized region
+/// ^^^^^^^^^^^ uninitialized region
+/// ```
+pub struct FrontSmallVec<T, const N: usize> {
+    repr: Repr<T, N>,
+}
+
+enum Repr<T, const N: usize> {
+    Inline {
+        buf: [MaybeUninit<T>; N],
+        len: usize,
+    },
+    Heap(FrontVec<T>),
+}
+
+impl<T, const N: usize> Repr<T, N> {
+    fn empty_inline() -> Self {
+        Repr::Inline {
+            buf: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+}
+
+impl<T, const N: usize> FrontSmallVec<T, N> {
+    pub fn new() -> Self {
+        Self {
+            repr: Repr::empty_inline(),
+        }
+    }
+
+    /// Creates an empty `FrontSmallVec` which can hold `cap` elements without
+    /// reallocating. Only allocates if `cap > N`.
+    pub fn with_capacity(cap: usize) -> Self {
+        if cap <= N {
+            Self::new()
+        } else {
+            Self {
+                repr: Repr::Heap(FrontVec::with_capacity(cap)),
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Inline { len, .. } => *len,
+            Repr::Heap(v) => v.len(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        match &self.repr {
+            Repr::Inline { .. } => N,
+            Repr::Heap(v) => v.capacity(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the elements have been moved onto the heap.
+    pub fn spilled(&self) -> bool {
+        matches!(self.repr, Repr::Heap(_))
+    }
+
+    /// Moves the inline elements into a heap-allocated `FrontVec` with room for
+    /// at least `min_cap` elements. Does nothing if already spilled.
+    fn spill(&mut self, min_cap: usize) {
+        let Repr::Inline { buf, len } = &mut self.repr else {
+            return;
+        };
+
+        let count = *len;
+        let mut heap = FrontVec::with_capacity(usize::max(min_cap, 2 * N));
+        let spare = heap.spare_capacity_mut();
+        let begin = spare.len() - count;
+
+        // SAFETY:
+        // * `buf[N - count..]` holds the initialized elements.
+        // * `spare[begin..]` is in bounds since `heap` has capacity for at
+        //   least `count` elements, and it belongs to a different allocation.
+        unsafe {
+            spare
+                .as_mut_ptr()
+                .add(begin)
+                .copy_from_nonoverlapping(buf.as_ptr().add(N - count), count);
+        }
+
+        // The elements were moved out, so they must not be dropped inline.
+        *len = 0;
+
+        // SAFETY: The last `count` slots of `heap` were just initialized.
+        unsafe {
+            heap.set_len(count);
+        }
+
+        self.repr = Repr::Heap(heap);
+    }
+
+    pub fn push_front(&mut self, val: T) {
+        match &mut self.repr {
+            Repr::Inline { buf, len } if *len < N => {
+                buf[N - *len - 1].write(val);
+                *len += 1;
+            }
+            Repr::Inline { .. } => {
+                self.spill(N + 1);
+                self.push_front(val);
+            }
+            Repr::Heap(v) => v.push_front(val),
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        match &mut self.repr {
+            Repr::Inline { len: 0, .. } => None,
+            Repr::Inline { buf, len } => {
+                // SAFETY: The front element at `N - len` is initialized, and
+                // it is forgotten by decrementing `len` right after.
+                let val = unsafe { buf[N - *len].assume_init_read() };
+                *len -= 1;
+                Some(val)
+            }
+            Repr::Heap(v) => v.pop_front(),
+        }
+    }
+
+    /// Returns false if capacity was already sufficient, returns true if the
+    /// elements were moved to a new buffer.
+    pub fn reserve_front(&mut self, extra_space_needed: usize) -> bool {
+        match &mut self.repr {
+            Repr::Inline { len, .. } => {
+                if N - *len >= extra_space_needed {
+                    false
+                } else {
+                    let needed = *len + extra_space_needed;
+                    self.spill(needed);
+                    true
+                }
+            }
+            Repr::Heap(v) => v.reserve_front(extra_space_needed),
+        }
+    }
+
+    /// Returns a mutable slice that references the uninitialized portion of the
+    /// underlying buffer.
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        match &mut self.repr {
+            Repr::Inline { buf, len } => &mut buf[..N - *len],
+            Repr::Heap(v) => v.spare_capacity_mut(),
+        }
+    }
+
+    /// # Safety
+    /// * `new_len` must be less than or equal to `capacity()`.
+    /// * The elements at `old_len..new_len` must be initialized.
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        match &mut self.repr {
+            Repr::Inline { len, .. } => *len = new_len,
+            Repr::Heap(v) => unsafe { v.set_len(new_len) },
+        }
+    }
+
+    pub fn extend_front(&mut self, items: impl DoubleEndedIterator<Item = T>) {
+        let (min_size, max_size) = items.size_hint();
+
+        // We know for sure there are no elements to add.
+        if max_size == Some(0) {
+            return;
+        }
+
+        self.reserve_front(min_size);
+
+        for item in items.rev() {
+            self.push_front(item)
+        }
+    }
+
+    /// Shortens the `FrontSmallVec`, keeping the **last** `len` elements and
+    /// dropping the rest.
+    /// If `len` is greater than the current length, this has no effect.
+    /// Note that this method has no effect on the allocated capacity, and never
+    /// moves spilled elements back inline.
+    pub fn truncate(&mut self, len: usize) {
+        match &mut self.repr {
+            Repr::Inline { buf, len: old_len } => {
+                let new_len = usize::min(len, *old_len);
+                let to_drop = &mut buf[N - *old_len..N - new_len];
+                // Forget the elements before dropping them, in case a `drop`
+                // panics.
+                *old_len = new_len;
+                // SAFETY: `to_drop` was part of the initialized region, and is
+                // no longer reachable now that the length has been updated.
+                unsafe {
+                    core::ptr::drop_in_place(to_drop as *mut [MaybeUninit<T>] as *mut [T]);
+                }
+            }
+            Repr::Heap(v) => v.truncate(len),
+        }
+    }
+
+    /// Converts into a [`FrontVec`], allocating only if the elements are
+    /// currently stored inline.
+    pub fn into_front_vec(mut self) -> FrontVec<T> {
+        let len = self.len();
+        self.spill(len);
+        match mem::replace(&mut self.repr, Repr::empty_inline()) {
+            Repr::Heap(v) => v,
+            Repr::Inline { .. } => unreachable!("`spill` always moves to the heap"),
+        }
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for FrontSmallVec<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        match &mut self.repr {
+            Repr::Inline { buf, len } => {
+                let init = &mut buf[N - *len..];
+                // SAFETY: The last `len` slots of `buf` are initialized.
+                unsafe { &mut *(init as *mut [MaybeUninit<T>] as *mut [T]) }
+            }
+            Repr::Heap(v) => v.as_mut(),
+        }
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for FrontSmallVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        match &self.repr {
+            Repr::Inline { buf, len } => {
+                let init = &buf[N - *len..];
+                // SAFETY: The last `len` slots of `buf` are initialized.
+                unsafe { &*(init as *const [MaybeUninit<T>] as *const [T]) }
+            }
+            Repr::Heap(v) => v.as_ref(),
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for FrontSmallVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl<T, const N: usize> DerefMut for FrontSmallVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut()
+    }
+}
+
+impl<T, const N: usize> Drop for FrontSmallVec<T, N> {
+    fn drop(&mut self) {
+        // A spilled `FrontVec` drops its own elements.
+        if let Repr::Inline { .. } = self.repr {
+            // SAFETY: `as_mut` covers exactly the initialized elements, and
+            // they're never accessed again.
+            unsafe {
+                core::ptr::drop_in_place(self.as_mut() as *mut [T]);
+            }
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for FrontSmallVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let slice: &[T] = self.as_ref();
+        slice.fmt(f)
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T]> for FrontSmallVec<T, N> {
+    fn from(slice: &[T]) -> Self {
+        let mut v = FrontSmallVec::with_capacity(slice.len());
+
+        for item in slice.iter().rev() {
+            v.push_front(item.clone());
+        }
+
+        v
+    }
+}
+
+impl<T: Clone, const N: usize, const M: usize> From<&[T; M]> for FrontSmallVec<T, N> {
+    fn from(array: &[T; M]) -> Self {
+        array.as_ref().into()
+    }
+}
+
+impl<T, const N: usize> From<FrontVec<T>> for FrontSmallVec<T, N> {
+    /// Note: The `FrontVec`'s buffer is kept even if the elements would fit
+    /// inline.
+    fn from(v: FrontVec<T>) -> Self {
+        Self {
+            repr: Repr::Heap(v),
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for FrontSmallVec<T, N> {
+    fn clone(&self) -> Self {
+        self.as_ref().into()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for FrontSmallVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for FrontSmallVec<T, N> {}
+
+impl<T, const N: usize> Default for FrontSmallVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{FrontSmallVec, FrontVec};
+use std::rc::Rc;
+
+#[test]
+fn stays_inline() {
+    let mut v = FrontSmallVec::<i32, 4>::new();
+    v.push_front(4);
+    v.push_front(3);
+    v.push_front(2);
+    v.push_front(1);
+    assert!(!v.spilled());
+    assert_eq!(v.capacity(), 4);
+    assert_eq!(v.as_ref(), &[1, 2, 3, 4]);
+
+    assert_eq!(v.pop_front(), Some(1));
+    v.push_front(0);
+    assert!(!v.spilled());
+    assert_eq!(v.as_ref(), &[0, 2, 3, 4]);
+}
+
+#[test]
+fn spills_to_heap() {
+    let mut v = FrontSmallVec::<String, 2>::new();
+    v.push_front("c".to_string());
+    v.push_front("b".to_string());
+    assert!(!v.spilled());
+    v.push_front("a".to_string());
+    assert!(v.spilled());
+    assert_eq!(v.len(), 3);
+    assert_eq!(v.as_ref(), &["a", "b", "c"]);
+
+    assert_eq!(v.pop_front().as_deref(), Some("a"));
+    assert_eq!(v.pop_front().as_deref(), Some("b"));
+    assert_eq!(v.pop_front().as_deref(), Some("c"));
+    assert_eq!(v.pop_front(), None);
+}
+
+#[test]
+fn reserve_front() {
+    let mut v = FrontSmallVec::<u8, 8>::from(&[1, 2, 3]);
+    assert!(!v.reserve_front(5));
+    assert!(!v.spilled());
+    assert!(v.reserve_front(6));
+    assert!(v.spilled());
+    assert!(v.capacity() >= 9);
+    assert_eq!(v.as_ref(), &[1, 2, 3]);
+}
+
+#[test]
+fn extend_and_index() {
+    let mut v = FrontSmallVec::<usize, 3>::new();
+    v.extend_front(3..6);
+    v.extend_front(0..3);
+    assert_eq!(v.as_ref(), &[0, 1, 2, 3, 4, 5]);
+    v[1] = 111;
+    assert_eq!(v[1], 111);
+    assert_eq!(&v[2..4], &[2, 3]);
+}
+
+#[test]
+fn drops_every_element() {
+    let rc = Rc::new(());
+
+    let mut inline = FrontSmallVec::<Rc<()>, 4>::new();
+    let mut spilled = FrontSmallVec::<Rc<()>, 2>::new();
+    for _ in 0..3 {
+        inline.push_front(rc.clone());
+        spilled.push_front(rc.clone());
+    }
+    assert_eq!(Rc::strong_count(&rc), 7);
+
+    inline.truncate(1);
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    drop(inline);
+    drop(spilled);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn into_front_vec() {
+    let v = FrontSmallVec::<i32, 4>::from(&[1, 2, 3]);
+    let fv: FrontVec<i32> = v.into_front_vec();
+    assert_eq!(fv.as_ref(), &[1, 2, 3]);
+
+    let v = FrontSmallVec::<i32, 4>::from(FrontVec::from(vec![1, 2, 3]));
+    assert!(v.spilled());
+    assert_eq!(v.into_front_vec().as_ref(), &[1, 2, 3]);
+}
+
+#[test]
+fn zero_inline_capacity() {
+    let mut v = FrontSmallVec::<i32, 0>::new();
+    assert_eq!(v.pop_front(), None);
+    v.push_front(1);
+    assert!(v.spilled());
+    assert_eq!(v.as_ref(), &[1]);
+}
@@ -0,0 +1,22 @@
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use alloc::string::String;
+
+use super::MULTIBYTE_PREFIXES;
+use crate::{front_vec::arbitrary_slack, FrontString};
+
+impl<'a> Arbitrary<'a> for FrontString {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let slack = arbitrary_slack(u)?;
+        let prefix = if u.arbitrary()? {
+            *u.choose(MULTIBYTE_PREFIXES)?
+        } else {
+            ""
+        };
+        let s: String = u.arbitrary()?;
+
+        let mut fs = FrontString::with_capacity(prefix.len() + s.len() + slack);
+        fs.push_str_front(s);
+        fs.push_str_front(prefix);
+        Ok(fs)
+    }
+}
@@ -0,0 +1,165 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{fmt, ops::Range};
+
+use crate::{FrontString, FrontVec};
+
+/// Collects prepended pieces without copying them, then materializes them into
+/// a [`FrontString`] (or a `String`) with a single, exactly sized allocation.
+///
+/// Prepending many fragments of unpredictable size onto a `FrontString` can
+/// reallocate (and copy the whole string) many times. The builder only records
+/// the pieces, so borrowed and owned strings are never copied until the final
+/// string is built.
+///
+/// # Example
+///
+/// ```
+/// # use front_vec::FrontStringBuilder;
+/// # use assert2::assert;
+/// let mut b = FrontStringBuilder::new();
+/// b.push_str_front("world!");
+/// b.push_fmt_front(format_args!("{}, ", "Hello"));
+/// b.push_char_front('>');
+/// let s = b.build_with_headroom(16);
+/// assert!(s == ">Hello, world!");
+/// assert!(s.capacity() == s.len() + 16);
+/// ```
+#[derive(Default)]
+pub struct FrontStringBuilder<'a> {
+    /// The pieces in the order they were prepended, so the *last* one ends up
+    /// at the front.
+    pieces: Vec<Piece<'a>>,
+    /// Chars and formatted text are written here instead of being allocated
+    /// individually.
+    scratch: String,
+    /// The total length in bytes.
+    len: usize,
+}
+
+enum Piece<'a> {
+    Borrowed(&'a str),
+    Owned(String),
+    Scratch(Range<usize>),
+}
+
+impl<'a> FrontStringBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The length in bytes of the string that would be built.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn push_piece(&mut self, piece: Piece<'a>, len: usize) {
+        if len > 0 {
+            self.pieces.push(piece);
+            self.len += len;
+        }
+    }
+
+    /// Records `s` to be prepended. Neither borrowed nor owned strings are
+    /// copied until the string is built.
+    pub fn push_str_front<S: Into<Cow<'a, str>>>(&mut self, s: S) {
+        match s.into() {
+            Cow::Borrowed(s) => self.push_piece(Piece::Borrowed(s), s.len()),
+            Cow::Owned(s) => {
+                let len = s.len();
+                self.push_piece(Piece::Owned(s), len);
+            }
+        }
+    }
+
+    pub fn push_char_front(&mut self, ch: char) {
+        let begin = self.scratch.len();
+        self.scratch.push(ch);
+        self.push_piece(Piece::Scratch(begin..self.scratch.len()), ch.len_utf8());
+    }
+
+    /// Records the formatted `args` to be prepended as a whole.
+    pub fn push_fmt_front(&mut self, args: fmt::Arguments<'_>) {
+        if let Some(s) = args.as_str() {
+            // Static strings can be borrowed for as long as we like.
+            self.push_str_front(s);
+            return;
+        }
+
+        let begin = self.scratch.len();
+        fmt::Write::write_fmt(&mut self.scratch, args).expect("formatting failed");
+        let end = self.scratch.len();
+        self.push_piece(Piece::Scratch(begin..end), end - begin);
+    }
+
+    /// Records `seg`, followed by `sep` if the builder is currently non-empty.
+    /// See [`FrontString::push_segment_front`].
+    pub fn push_segment_front<S: Into<Cow<'a, str>>>(&mut self, sep: &'a str, seg: S) {
+        if !self.is_empty() {
+            self.push_str_front(sep);
+        }
+        self.push_str_front(seg);
+    }
+
+    /// Records every item of `segs` as if by calling
+    /// [`push_segment_front`](Self::push_segment_front) on each in turn. See
+    /// [`FrontString::prepend_join`].
+    pub fn prepend_join<I>(&mut self, sep: &'a str, segs: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'a, str>>,
+    {
+        for seg in segs {
+            self.push_segment_front(sep, seg);
+        }
+    }
+
+    fn piece_str<'s>(&'s self, piece: &'s Piece<'a>) -> &'s str {
+        match piece {
+            Piece::Borrowed(s) => s,
+            Piece::Owned(s) => s,
+            Piece::Scratch(range) => &self.scratch[range.clone()],
+        }
+    }
+
+    /// Builds a `FrontString` whose capacity is exactly its length.
+    pub fn build(&self) -> FrontString {
+        self.build_with_headroom(0)
+    }
+
+    /// Builds a `FrontString` which can have `headroom` more bytes prepended
+    /// without reallocating.
+    pub fn build_with_headroom(&self, headroom: usize) -> FrontString {
+        let mut buf = FrontVec::with_capacity(self.len + headroom);
+        for piece in &self.pieces {
+            buf.extend_front_from_slice(self.piece_str(piece).as_bytes());
+        }
+        // SAFETY: Only whole `&str`s were written, so `buf` is valid UTF8.
+        unsafe { FrontString::from_utf8_unchecked(buf) }
+    }
+
+    /// Builds a `String` whose capacity is exactly its length.
+    pub fn into_string(self) -> String {
+        let mut s = String::with_capacity(self.len);
+        for piece in self.pieces.iter().rev() {
+            s.push_str(self.piece_str(piece));
+        }
+        s
+    }
+}
+
+impl From<FrontStringBuilder<'_>> for FrontString {
+    fn from(builder: FrontStringBuilder<'_>) -> Self {
+        builder.build()
+    }
+}
+
+impl fmt::Debug for FrontStringBuilder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pieces = self.pieces.iter().rev().map(|piece| self.piece_str(piece));
+        f.debug_list().entries(pieces).finish()
+    }
+}
@@ -1,4 +1,5 @@
-use std::{fmt, mem::MaybeUninit, ops::Deref};
+use alloc::{string::String, vec::Vec};
+use core::{fmt, mem::MaybeUninit, ops::Deref};
 
 use crate::FrontVec;
 
@@ -18,6 +19,50 @@ impl FrontString {
         }
     }
 
+    /// Converts a `FrontVec<u8>` to a `FrontString` without checking that it
+    /// contains valid UTF8.
+    ///
+    /// # Safety
+    /// The bytes in `buf` must be valid UTF8.
+    pub unsafe fn from_utf8_unchecked(buf: FrontVec<u8>) -> Self {
+        Self { buf }
+    }
+
+    /// Formats `args` into a `FrontString` with a capacity of exactly its
+    /// length. This backs the [`front_string!`](crate::front_string!) macro.
+    ///
+    /// The arguments are formatted twice: once to measure the length, and once
+    /// to write directly into the single allocation.
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        if let Some(s) = args.as_str() {
+            return Self::from(s);
+        }
+
+        let mut counter = ByteCounter(0);
+        fmt::Write::write_fmt(&mut counter, args).expect("formatting failed");
+        let len = counter.0;
+
+        let mut buf = FrontVec::with_capacity(len);
+        let mut writer = SpareWriter {
+            spare: buf.spare_capacity_mut(),
+            pos: 0,
+        };
+        // With an exact capacity, the front of the spare capacity is also where
+        // the contents must begin, so the output can be written front to back.
+        // A `Display` impl might not write the same thing twice though, in
+        // which case fall back to an intermediate `String`.
+        if fmt::Write::write_fmt(&mut writer, args).is_err() || writer.pos != len {
+            return Self::from(alloc::fmt::format(args));
+        }
+
+        // SAFETY: All `len` bytes were just written, and only whole `&str`s
+        // were written, so they're valid UTF8.
+        unsafe {
+            buf.set_len(len);
+            Self::from_utf8_unchecked(buf)
+        }
+    }
+
     pub fn capacity(&self) -> usize {
         self.buf.capacity()
     }
@@ -29,6 +74,12 @@ impl FrontString {
         self.buf.reserve_front(additional)
     }
 
+    /// Like `reserve_front`, but grows geometrically, for callers which
+    /// prepend many small pieces.
+    pub(super) fn reserve_front_amortized(&mut self, additional: usize) {
+        self.buf.reserve_front_amortized(additional);
+    }
+
     pub fn push_char_front(&mut self, ch: char) {
         let mut buf = [0; 4];
         let bytes = ch.encode_utf8(&mut buf).bytes();
@@ -54,6 +105,88 @@ impl FrontString {
         self.buf.extend_front(s.as_ref().bytes());
     }
 
+    /// Prepends `seg`, followed by `sep` if the string is currently non-empty.
+    /// In particular, an empty `seg` leaves an empty string empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontString;
+    /// # use assert2::assert;
+    /// let mut s = FrontString::new();
+    /// s.push_segment_front(".", "c");
+    /// s.push_segment_front(".", "b");
+    /// s.push_segment_front(".", "a");
+    /// assert!(s == "a.b.c");
+    /// ```
+    pub fn push_segment_front<S: AsRef<str>>(&mut self, sep: &str, seg: S) {
+        self.prepend_join(sep, core::iter::once(seg.as_ref()));
+    }
+
+    /// Prepends every item of `segs` as if by calling
+    /// [`push_segment_front`](Self::push_segment_front) on each in turn, so the
+    /// *last* item ends up at the very front.
+    ///
+    /// The total length is computed up front (which is why the iterator must
+    /// be `Clone`), so at most one reallocation happens.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontString;
+    /// # use assert2::assert;
+    /// let mut s = FrontString::from("d");
+    /// s.prepend_join("::", ["c", "b", "a"]);
+    /// assert!(s == "a::b::c::d");
+    /// ```
+    pub fn prepend_join<I>(&mut self, sep: &str, segs: I)
+    where
+        I: IntoIterator,
+        I::IntoIter: Clone,
+        I::Item: AsRef<str>,
+    {
+        let segs = segs.into_iter();
+
+        // A separator goes after every segment prepended while the string is
+        // non-empty, so empty segments only get one once something precedes
+        // them.
+        let mut non_empty = !self.is_empty();
+        let (sep_count, segs_len) = segs.clone().fold((0, 0), |(count, len), seg| {
+            let seg = seg.as_ref();
+            let count = count + usize::from(non_empty);
+            non_empty |= !seg.is_empty();
+            (count, len + seg.len())
+        });
+        self.reserve_front(segs_len + sep_count * sep.len());
+
+        let mut non_empty = !self.is_empty();
+        let spare = self.spare_capacity_mut();
+        let mut begin = spare.len();
+
+        // Write each segment (and its trailing separator) just in front of the
+        // previous one. The slicing is bounds-checked, so an iterator that
+        // yields longer items the second time around panics instead of writing
+        // out of bounds. The length is only updated once everything is written.
+        for seg in segs {
+            let seg = seg.as_ref();
+            if non_empty {
+                begin = write_bytes_before(spare, begin, sep.as_bytes());
+            }
+            begin = write_bytes_before(spare, begin, seg.as_bytes());
+            non_empty |= !seg.is_empty();
+        }
+
+        let written = spare.len() - begin;
+
+        // SAFETY:
+        // 1. `spare[begin..]` was just initialized, and it directly precedes the
+        //    initialized region, so the new length covers only initialized bytes.
+        // 2. Only whole `&str`s were written, so the contents are valid UTF8.
+        unsafe {
+            self.set_len(self.len() + written);
+        }
+    }
+
     /// Returns a mutable slice that references the uninitialized portion of the
     /// underlying buffer.
     pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<u8>] {
@@ -123,12 +256,99 @@ impl FrontString {
     pub fn truncate(&mut self, new_len: usize) {
         let new_len = usize::min(self.len(), new_len);
 
-        if !self.is_char_boundary(new_len) {
+        // The kept bytes start at `len - new_len`.
+        if !self.is_char_boundary(self.len() - new_len) {
             panic!("new length is not on a char boundary");
         }
 
         self.buf.truncate(new_len);
     }
+
+    /// Moves all of `other` onto the front of `self`, leaving `other` empty.
+    /// See [`FrontVec::prepend`].
+    pub fn prepend(&mut self, other: &mut FrontString) {
+        // Concatenating valid UTF8 yields valid UTF8.
+        self.buf.prepend(&mut other.buf);
+    }
+
+    /// Moves all of `other` onto the back of `self`, leaving `other` empty.
+    /// See [`FrontVec::append`].
+    pub fn append(&mut self, other: &mut FrontString) {
+        // Concatenating valid UTF8 yields valid UTF8.
+        self.buf.append(&mut other.buf);
+    }
+
+    /// Splits the string in two at the byte index `at`. `self` keeps
+    /// `[0, at)` and `[at, len)` is returned.
+    ///
+    /// # Panics
+    /// Panics if `at` does not lie on a `char` boundary, or if it's past the
+    /// end of the string.
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> FrontString {
+        assert!(self.is_char_boundary(at), "`at` is not on a char boundary");
+        Self {
+            buf: self.buf.split_off(at),
+        }
+    }
+
+    /// Splits the string in two at the byte index `at`. `[0, at)` is returned
+    /// and `self` keeps `[at, len)` in place.
+    ///
+    /// # Panics
+    /// Panics if `at` does not lie on a `char` boundary, or if it's past the
+    /// end of the string.
+    #[track_caller]
+    pub fn split_off_front(&mut self, at: usize) -> FrontString {
+        assert!(self.is_char_boundary(at), "`at` is not on a char boundary");
+        Self {
+            buf: self.buf.split_off_front(at),
+        }
+    }
+}
+
+/// Copies `bytes` into `spare[..end]` so that they end right before `end`, and
+/// returns the index of the first byte written.
+fn write_bytes_before(spare: &mut [MaybeUninit<u8>], end: usize, bytes: &[u8]) -> usize {
+    let begin = end
+        .checked_sub(bytes.len())
+        .expect("not enough reserved space");
+
+    for (slot, &byte) in spare[begin..end].iter_mut().zip(bytes) {
+        slot.write(byte);
+    }
+
+    begin
+}
+
+/// Counts the bytes written without storing them.
+struct ByteCounter(usize);
+
+impl fmt::Write for ByteCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Writes front to back into spare capacity, failing instead of growing.
+struct SpareWriter<'a> {
+    spare: &'a mut [MaybeUninit<u8>],
+    pos: usize,
+}
+
+impl fmt::Write for SpareWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let dst = self
+            .spare
+            .get_mut(self.pos..self.pos + s.len())
+            .ok_or(fmt::Error)?;
+        for (slot, &byte) in dst.iter_mut().zip(s.as_bytes()) {
+            slot.write(byte);
+        }
+        self.pos += s.len();
+        Ok(())
+    }
 }
 
 impl From<&str> for FrontString {
@@ -159,7 +379,7 @@ impl Deref for FrontString {
     fn deref(&self) -> &Self::Target {
         let byte_slice = self.buf.as_ref();
         // SAFETY: Because `self.buf` always contains valid UTF8, this is safe.
-        unsafe { std::str::from_utf8_unchecked(byte_slice) }
+        unsafe { core::str::from_utf8_unchecked(byte_slice) }
     }
 }
 
@@ -0,0 +1,93 @@
+use std::io::{self, BufRead, Read};
+
+use crate::FrontString;
+
+impl FrontString {
+    /// Returns a reader which consumes bytes from the front of the string.
+    ///
+    /// Reads may stop in the middle of a `char`, e.g. through [`Read::bytes`].
+    /// Since the string must stay valid UTF8, a partly read `char` is only
+    /// removed from it once the reader moves past its last byte.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontString;
+    /// # use assert2::assert;
+    /// use std::io::Read;
+    ///
+    /// let mut s = FrontString::from("é!");
+    /// let mut reader = s.reader();
+    /// let mut byte = [0];
+    /// reader.read_exact(&mut byte).unwrap();
+    /// assert!(byte == [0xc3]);
+    /// // The rest of "é" hasn't been read yet.
+    /// assert!(s == "é!");
+    /// ```
+    pub fn reader(&mut self) -> FrontStringReader<'_> {
+        FrontStringReader { s: self, read: 0 }
+    }
+}
+
+/// Reads from the front of a [`FrontString`], see [`FrontString::reader`].
+#[derive(Debug)]
+pub struct FrontStringReader<'a> {
+    s: &'a mut FrontString,
+    /// The number of bytes of the string's first `char` which were already
+    /// read. Always less than its length.
+    read: usize,
+}
+
+impl Read for FrontStringReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = usize::min(buf.len(), available.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let rest = &self.s.as_bytes()[self.read..];
+        let n = rest.len();
+        buf.extend_from_slice(rest);
+        self.consume(n);
+        Ok(n)
+    }
+
+    /// Fails with [`io::ErrorKind::InvalidData`] without reading anything if
+    /// the first `char` was partly read.
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        if self.read > 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the rest of a partly read char is not valid UTF8",
+            ));
+        }
+
+        let n = self.s.len();
+        buf.push_str(self.s);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl BufRead for FrontStringReader<'_> {
+    /// Returns all bytes which weren't read yet.
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(&self.s.as_bytes()[self.read..])
+    }
+
+    /// Moves `amt` bytes forward, removing every `char` that was read
+    /// completely from the front of the string in O(1).
+    fn consume(&mut self, amt: usize) {
+        let pos = usize::min(self.read + amt, self.s.len());
+        let mut whole = pos;
+        while !self.s.is_char_boundary(whole) {
+            whole -= 1;
+        }
+
+        self.s.truncate(self.s.len() - whole);
+        self.read = pos - whole;
+    }
+}
@@ -1,6 +1,23 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod builder;
 mod front_string;
+#[cfg(feature = "std")]
+mod io;
+mod num;
+#[cfg(feature = "proptest")]
+mod proptest;
 
 #[cfg(test)]
 mod tests;
 
+pub use builder::FrontStringBuilder;
 pub use front_string::*;
+#[cfg(feature = "std")]
+pub use io::FrontStringReader;
+pub use num::{Float, Integer};
+
+/// Generated strings sometimes start with one of these, so that fuzzers hit
+/// char boundaries right at the front.
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+const MULTIBYTE_PREFIXES: &[&str] = &["é", "€", "𝄞", "いろは", "e\u{301}"];
@@ -0,0 +1,293 @@
+//! Number formatting straight into a `FrontString`'s headroom.
+//!
+//! Integers are formatted least significant digit first, which is exactly the
+//! order a `FrontString` grows in, so the digits are written right to left
+//! into the spare capacity without any intermediate buffer. Floats are
+//! formatted once into a stack buffer by their `Display` impl, then copied.
+
+use core::fmt::{self, Write as _};
+
+use crate::FrontString;
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+mod sealed {
+    pub trait Integer: Copy {
+        type Magnitude: Magnitude;
+
+        /// Returns whether `self` is negative, and its absolute value.
+        fn into_parts(self) -> (bool, Self::Magnitude);
+    }
+
+    pub trait Magnitude: Copy {
+        /// Returns `(self / radix, self % radix)`.
+        fn div_rem(self, radix: u32) -> (Self, u32);
+
+        fn is_zero(self) -> bool;
+
+        /// Returns the number of digits of `self` in the given radix.
+        fn digits(self, radix: u32) -> usize;
+    }
+
+    pub trait Float: core::fmt::Display {}
+}
+
+/// A primitive integer type, which can be formatted with
+/// [`FrontString::prepend_int`] and friends.
+///
+/// This trait is sealed: it can't be implemented outside of this crate.
+pub trait Integer: sealed::Integer {}
+
+/// A primitive floating point type, which can be formatted with
+/// [`FrontString::prepend_float`].
+///
+/// This trait is sealed: it can't be implemented outside of this crate.
+pub trait Float: sealed::Float {}
+
+macro_rules! impl_magnitude {
+    ($($t:ty)*) => {$(
+        impl sealed::Magnitude for $t {
+            #[inline]
+            fn div_rem(self, radix: u32) -> (Self, u32) {
+                let radix = radix as $t;
+                (self / radix, (self % radix) as u32)
+            }
+
+            #[inline]
+            fn is_zero(self) -> bool {
+                self == 0
+            }
+
+            #[inline]
+            fn digits(self, radix: u32) -> usize {
+                match self.checked_ilog(radix as $t) {
+                    Some(log) => log as usize + 1,
+                    None => 1,
+                }
+            }
+        }
+    )*};
+}
+
+impl_magnitude!(u32 u64 u128);
+
+// Small integers are widened to `u32`, whose division is as fast as it gets.
+macro_rules! impl_integer {
+    ($($unsigned:ty, $signed:ty => $magnitude:ty;)*) => {$(
+        impl sealed::Integer for $unsigned {
+            type Magnitude = $magnitude;
+
+            #[inline]
+            fn into_parts(self) -> (bool, $magnitude) {
+                (false, self as $magnitude)
+            }
+        }
+
+        impl sealed::Integer for $signed {
+            type Magnitude = $magnitude;
+
+            #[inline]
+            fn into_parts(self) -> (bool, $magnitude) {
+                (self < 0, self.unsigned_abs() as $magnitude)
+            }
+        }
+
+        impl Integer for $unsigned {}
+        impl Integer for $signed {}
+    )*};
+}
+
+impl_integer! {
+    u8, i8 => u32;
+    u16, i16 => u32;
+    u32, i32 => u32;
+    u64, i64 => u64;
+    usize, isize => u64;
+    u128, i128 => u128;
+}
+
+impl sealed::Float for f32 {}
+impl sealed::Float for f64 {}
+impl Float for f32 {}
+impl Float for f64 {}
+
+impl FrontString {
+    /// Prepends `n` in decimal, exactly like its `Display` impl.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontString;
+    /// # use assert2::assert;
+    /// let mut s = FrontString::from(" ms");
+    /// s.prepend_int(-42);
+    /// s.push_str_front("took ");
+    /// assert!(s == "took -42 ms");
+    /// ```
+    pub fn prepend_int<I: Integer>(&mut self, n: I) {
+        self.prepend_int_with(n, 10, 0, None);
+    }
+
+    /// Prepends `n` in the given radix, with lowercase letters for digits
+    /// above 9 and a leading `-` if negative.
+    ///
+    /// Unlike the `{:x}`-style formatting traits, negative numbers are printed
+    /// as such rather than in two's complement.
+    ///
+    /// # Panics
+    /// Panics if `radix` isn't in `2..=36`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontString;
+    /// # use assert2::assert;
+    /// let mut s = FrontString::new();
+    /// s.prepend_int_radix(255u8, 16);
+    /// s.push_str_front("0x");
+    /// assert!(s == "0xff");
+    /// ```
+    pub fn prepend_int_radix<I: Integer>(&mut self, n: I, radix: u32) {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in `2..=36`, not {radix}"
+        );
+        self.prepend_int_with(n, radix, 0, None);
+    }
+
+    /// Prepends `n` in decimal, padded with zeros to at least `width` bytes,
+    /// like `format!("{n:0width$}")`. The sign counts towards the width.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontString;
+    /// # use assert2::assert;
+    /// let mut s = FrontString::new();
+    /// s.prepend_int_padded(7, 3);
+    /// s.push_str_front("frame_");
+    /// assert!(s == "frame_007");
+    /// ```
+    pub fn prepend_int_padded<I: Integer>(&mut self, n: I, width: usize) {
+        self.prepend_int_with(n, 10, width, None);
+    }
+
+    /// Prepends `n` in decimal, with `sep` between groups of three digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontString;
+    /// # use assert2::assert;
+    /// let mut s = FrontString::from(" bytes");
+    /// s.prepend_int_grouped(1234567u32, ',');
+    /// assert!(s == "1,234,567 bytes");
+    /// ```
+    pub fn prepend_int_grouped<I: Integer>(&mut self, n: I, sep: char) {
+        self.prepend_int_with(n, 10, 0, Some(sep));
+    }
+
+    fn prepend_int_with<I: Integer>(&mut self, n: I, radix: u32, width: usize, sep: Option<char>) {
+        use sealed::Magnitude;
+
+        let (negative, mut magnitude) = n.into_parts();
+
+        let digits = magnitude.digits(radix);
+        let mut sep_buf = [0; 4];
+        let sep = sep.map(|sep| &*sep.encode_utf8(&mut sep_buf));
+        let seps_len = sep.map_or(0, |sep| (digits - 1) / 3 * sep.len());
+        let sign_len = usize::from(negative);
+        // Exact, so that small numbers in wide types fit in small headrooms,
+        // but geometric once the headroom runs out, so that prepending many
+        // numbers stays amortized O(1).
+        self.reserve_front_amortized(usize::max(width, digits + seps_len + sign_len));
+
+        let spare = self.spare_capacity_mut();
+        let end = spare.len();
+        let mut begin = end;
+        let mut written = 0;
+        loop {
+            if let Some(sep) = sep {
+                if written > 0 && written % 3 == 0 {
+                    for &byte in sep.as_bytes().iter().rev() {
+                        begin -= 1;
+                        spare[begin].write(byte);
+                    }
+                }
+            }
+
+            let (rest, digit) = magnitude.div_rem(radix);
+            begin -= 1;
+            spare[begin].write(DIGITS[digit as usize]);
+            written += 1;
+
+            magnitude = rest;
+            if magnitude.is_zero() {
+                break;
+            }
+        }
+
+        while end - begin + sign_len < width {
+            begin -= 1;
+            spare[begin].write(b'0');
+        }
+        if negative {
+            begin -= 1;
+            spare[begin].write(b'-');
+        }
+
+        // SAFETY: `spare[begin..]` was just written with ASCII digits and signs
+        // (and whole `char`s as separators), right before the initialized
+        // region. The indexing above is bounds-checked, and the reservation
+        // covers every byte written anyway.
+        unsafe {
+            self.set_len(self.len() + (end - begin));
+        }
+    }
+
+    /// Prepends `x` exactly like its `Display` impl.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontString;
+    /// # use assert2::assert;
+    /// let mut s = FrontString::from("s");
+    /// s.prepend_float(0.25);
+    /// assert!(s == "0.25s");
+    /// ```
+    pub fn prepend_float<F: Float>(&mut self, x: F) {
+        let mut buf = FloatBuf {
+            bytes: [0; FLOAT_BUF_LEN],
+            len: 0,
+        };
+        write!(buf, "{x}").expect("float is too long to format");
+        // SAFETY: Only whole `&str`s were written.
+        let s = unsafe { core::str::from_utf8_unchecked(&buf.bytes[..buf.len]) };
+
+        self.reserve_front_amortized(s.len());
+        self.push_str_front(s);
+    }
+}
+
+/// Longer than any `f32` or `f64` formatted with `Display`, the longest of
+/// which (small negative subnormals) take 327 bytes.
+const FLOAT_BUF_LEN: usize = 512;
+
+/// A float formatted on the stack, so that it's only formatted once.
+struct FloatBuf {
+    bytes: [u8; FLOAT_BUF_LEN],
+    len: usize,
+}
+
+impl fmt::Write for FloatBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use ::proptest::{collection::vec, option, prelude::*, sample::select};
+
+use super::MULTIBYTE_PREFIXES;
+use crate::{front_vec::proptest_slack, FrontString};
+
+impl Arbitrary for FrontString {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        let prefix = option::of(select(MULTIBYTE_PREFIXES));
+        let rest = vec(any::<char>(), 0..64);
+        (prefix, rest, proptest_slack())
+            .prop_map(|(prefix, rest, slack)| {
+                let prefix = prefix.unwrap_or("");
+                let rest: String = rest.into_iter().collect();
+                let mut fs = FrontString::with_capacity(prefix.len() + rest.len() + slack);
+                fs.push_str_front(rest);
+                fs.push_str_front(prefix);
+                fs
+            })
+            .boxed()
+    }
+}
@@ -69,6 +69,15 @@ fn bad_truncation() {
     s.truncate(end.len() - 1);
 }
 
+#[should_panic = "not on a char boundary"]
+#[test]
+fn truncation_inside_leading_char() {
+    // Keeping the last 3 bytes of "ぬa" would start in the middle of "ぬ",
+    // even though byte 3 of the whole string is a boundary.
+    let mut s = FrontString::from("ぬa");
+    s.truncate(3);
+}
+
 #[test]
 fn extend_truncate_extend() {
     let mut s = FrontString::from("tion");
@@ -80,3 +89,426 @@ fn extend_truncate_extend() {
     s.push_str_front("evolu");
     assert!(s == "evolution");
 }
+
+#[test]
+fn push_segment_front() {
+    let mut s = FrontString::new();
+    s.push_segment_front(".", "c");
+    assert!(s == "c");
+    s.push_segment_front(".", "b");
+    s.push_segment_front(".", "a");
+    assert!(s == "a.b.c");
+}
+
+#[test]
+fn prepend_join_reserves_once() {
+    let mut s = FrontString::from("ぬ");
+    let cap_before = s.capacity();
+    s.prepend_join("→", ["る", "を", "いろは"]);
+    assert!(s == "いろは→を→る→ぬ");
+    assert!(s.capacity() == cap_before + "いろは→を→る→".len());
+}
+
+#[test]
+fn prepend_join_onto_empty() {
+    let mut s = FrontString::new();
+    s.prepend_join(
+        ", ",
+        vec![String::from("3"), String::from("2"), String::from("1")],
+    );
+    assert!(s == "1, 2, 3");
+
+    let mut s = FrontString::new();
+    s.prepend_join(", ", Vec::<&str>::new());
+    assert!(s == "");
+}
+
+#[test]
+fn prepend_join_empty_segments() {
+    let mut s = FrontString::new();
+    s.prepend_join("/", ["", "b", ""]);
+    assert!(s == "/b");
+
+    let mut s = FrontString::from("c");
+    s.prepend_join("/", ["", "b", ""]);
+    assert!(s == "/b//c");
+}
+
+#[test]
+fn truncate_to_boundary_inside_leading_char() {
+    // The kept suffix starts on a boundary even though byte 3 of the whole
+    // string doesn't.
+    let mut s = FrontString::from("aぬ");
+    s.truncate(3);
+    assert!(s == "ぬ");
+}
+
+#[test]
+fn prepend_append_split() {
+    let mut s = FrontString::from("world");
+    s.prepend(&mut FrontString::from("hello "));
+    s.append(&mut FrontString::from("!"));
+    assert!(s == "hello world!");
+
+    let back = s.split_off(5);
+    assert!(s == "hello");
+    assert!(back == " world!");
+
+    let mut s = FrontString::from("いろは");
+    let front = s.split_off_front(3);
+    assert!(front == "い");
+    assert!(s == "ろは");
+}
+
+#[should_panic]
+#[test]
+fn split_off_inside_char() {
+    FrontString::from("いろは").split_off(1);
+}
+
+#[test]
+fn builder_matches_front_string() {
+    use super::FrontStringBuilder;
+    use std::borrow::Cow;
+
+    let owned = String::from("owned");
+    let mut b = FrontStringBuilder::new();
+    let mut s = FrontString::new();
+
+    b.push_str_front("tail");
+    s.push_str_front("tail");
+    b.push_str_front(owned.clone());
+    s.push_str_front(&owned);
+    b.push_str_front(Cow::Borrowed(""));
+    b.push_char_front('ß');
+    s.push_char_front('ß');
+    b.push_fmt_front(format_args!("{}-{:02}", "x", 7));
+    s.push_str_front(format!("{}-{:02}", "x", 7));
+    b.push_segment_front("/", "seg");
+    s.push_segment_front("/", "seg");
+    b.prepend_join("::", ["c", "b", "a"]);
+    s.prepend_join("::", ["c", "b", "a"]);
+
+    assert!(b.len() == s.len());
+    let built = b.build();
+    assert!(built == &*s);
+    assert!(built.capacity() == s.len());
+    assert!(format!("{b:?}").starts_with(r#"["a", "::", "b""#));
+
+    let string = b.into_string();
+    assert!(string == *s);
+    assert!(string.capacity() == s.len());
+}
+
+#[test]
+fn builder_joins_like_front_string() {
+    use super::FrontStringBuilder;
+
+    let cases: [(&str, &[&str]); 6] = [
+        ("", &[]),
+        ("", &["", ""]),
+        ("", &["", "b", ""]),
+        ("c", &["", "b", ""]),
+        ("c", &[""]),
+        ("", &["a", "", "b"]),
+    ];
+    for (init, segs) in cases {
+        let mut s = FrontString::from(init);
+        s.prepend_join("/", segs.iter().copied());
+
+        let mut b = FrontStringBuilder::new();
+        b.push_str_front(init);
+        b.prepend_join("/", segs.iter().copied());
+        assert!(b.build() == &*s, "{init:?} {segs:?}");
+
+        let mut s = FrontString::from(init);
+        let mut b = FrontStringBuilder::new();
+        b.push_str_front(init);
+        for &seg in segs {
+            s.push_segment_front("/", seg);
+            b.push_segment_front("/", seg);
+        }
+        assert!(b.build() == &*s, "{init:?} {segs:?}");
+    }
+}
+
+#[test]
+fn empty_builder() {
+    use super::FrontStringBuilder;
+
+    let mut b = FrontStringBuilder::new();
+    b.prepend_join(",", ["only"]);
+    assert!(b.build() == "only");
+
+    let b = FrontStringBuilder::new();
+    assert!(b.is_empty());
+    assert!(b.build_with_headroom(8).capacity() == 8);
+    assert!(b.into_string().is_empty());
+}
+
+#[test]
+fn front_string_macro() {
+    use std::fmt;
+
+    let s = crate::front_string!("static");
+    assert!(s == "static");
+
+    let s = crate::front_string!("{}-{:>4}", 'ß', 42);
+    assert!(s == "ß-  42");
+    assert!(s.capacity() == s.len());
+
+    // Writes something different every time it's formatted.
+    struct Growing(std::cell::Cell<usize>);
+    impl fmt::Display for Growing {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            write!(f, "{}", "x".repeat(self.0.get()))
+        }
+    }
+    let g = Growing(Default::default());
+    let s = crate::front_string!("{g}");
+    assert!(s == "xxx");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn buf_read_lines() {
+    use std::io::BufRead;
+
+    let mut s = FrontString::from("いろは\nにほへと\n");
+    let lines: Vec<String> = s.reader().lines().map(Result::unwrap).collect();
+    assert!(lines == ["いろは", "にほへと"]);
+    assert!(s == "");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn read_into_small_buffers() {
+    use std::io::Read;
+
+    let mut s = FrontString::from("aぬb");
+    let mut reader = s.reader();
+    let mut buf = [0; 2];
+    assert!(reader.read(&mut buf).unwrap() == 2);
+    assert!(buf == [b'a', 0xe3]);
+    assert!(reader.read(&mut buf).unwrap() == 2);
+    assert!(buf == [0x81, 0xac]);
+    assert!(s == "b");
+
+    // Dropping the reader in the middle of a char leaves the char whole.
+    let mut s = FrontString::from("aぬb");
+    s.reader().read_exact(&mut buf).unwrap();
+    assert!(s == "ぬb");
+    assert!(s.len() == 4);
+
+    let mut rest = String::new();
+    s.reader().read_to_string(&mut rest).unwrap();
+    assert!(rest == "ぬb");
+    assert!(s == "");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn read_bytes_of_non_ascii() {
+    use std::io::Read;
+
+    let text = "aぬ𝄞é";
+    let mut s = FrontString::from(text);
+    let bytes: Vec<u8> = s.reader().bytes().map(Result::unwrap).collect();
+    assert!(bytes == text.as_bytes());
+    assert!(s == "");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn consume_mid_char() {
+    use std::io::{BufRead, ErrorKind, Read};
+
+    let mut s = FrontString::from("ぬ𝄞");
+    let mut reader = s.reader();
+    assert!(reader.fill_buf().unwrap() == "ぬ𝄞".as_bytes());
+    reader.consume(1);
+    assert!(reader.fill_buf().unwrap() == &"ぬ𝄞".as_bytes()[1..]);
+    assert!(
+        reader
+            .read_to_string(&mut String::new())
+            .unwrap_err()
+            .kind()
+            == ErrorKind::InvalidData
+    );
+
+    // Through the rest of "ぬ" and into "𝄞".
+    reader.consume(4);
+    assert!(reader.fill_buf().unwrap() == &"𝄞".as_bytes()[2..]);
+
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert!(rest == &"𝄞".as_bytes()[2..]);
+    assert!(reader.fill_buf().unwrap() == b"");
+    assert!(s == "");
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_strings_are_valid() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let mut saw_multibyte_front = false;
+    for seed in 0..=255u8 {
+        let data: Vec<u8> = (0..64).map(|i| seed.wrapping_mul(17) ^ i).collect();
+        let mut u = Unstructured::new(&data);
+        let mut s = FrontString::arbitrary(&mut u).unwrap();
+        assert!(std::str::from_utf8(s.as_bytes()).is_ok());
+        saw_multibyte_front |= !s.is_char_boundary(1);
+
+        let before = s.to_string();
+        s.push_str_front("ab");
+        assert!(s == format!("ab{before}").as_str());
+    }
+    assert!(saw_multibyte_front);
+}
+
+#[cfg(feature = "proptest")]
+mod prop {
+    use super::FrontString;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn arbitrary_strings_are_valid(mut s in any::<FrontString>(), c: char) {
+            prop_assert!(s.capacity() >= s.len());
+            prop_assert!(std::str::from_utf8(s.as_bytes()).is_ok());
+            let before = s.to_string();
+            s.push_char_front(c);
+            prop_assert_eq!(s.to_string(), format!("{c}{before}"));
+        }
+    }
+}
+
+mod num {
+    use super::FrontString;
+    use assert2::assert;
+
+    fn prepended(f: impl FnOnce(&mut FrontString)) -> String {
+        let mut s = FrontString::from("|");
+        f(&mut s);
+        let s = s.to_string();
+        s.strip_suffix('|').unwrap().to_owned()
+    }
+
+    #[test]
+    fn ints_match_display() {
+        macro_rules! check {
+            ($($t:ty)*) => {$(
+                for n in [<$t>::MIN, <$t>::MIN / 7, 0 as $t, 1 as $t, 9 as $t, 10 as $t, <$t>::MAX / 3, <$t>::MAX] {
+                    assert!(prepended(|s| s.prepend_int(n)) == n.to_string());
+                    assert!(prepended(|s| s.prepend_int_padded(n, 6)) == format!("{n:06}"));
+                }
+            )*};
+        }
+        check!(u8 i8 u16 i16 u32 i32 u64 i64 usize isize u128 i128);
+    }
+
+    #[test]
+    fn radix() {
+        assert!(prepended(|s| s.prepend_int_radix(u128::MAX, 2)) == format!("{:b}", u128::MAX));
+        assert!(prepended(|s| s.prepend_int_radix(0xdead_beefu32, 16)) == "deadbeef");
+        assert!(prepended(|s| s.prepend_int_radix(-255i16, 16)) == "-ff");
+        assert!(prepended(|s| s.prepend_int_radix(35u8, 36)) == "z");
+        assert!(prepended(|s| s.prepend_int_radix(i64::MIN, 7)) == "-22341010611245052052301");
+    }
+
+    #[test]
+    #[should_panic = "radix"]
+    fn radix_out_of_range() {
+        FrontString::new().prepend_int_radix(1u8, 37);
+    }
+
+    #[test]
+    fn padded() {
+        assert!(prepended(|s| s.prepend_int_padded(-42, 5)) == "-0042");
+        assert!(prepended(|s| s.prepend_int_padded(12345, 3)) == "12345");
+        assert!(prepended(|s| s.prepend_int_padded(0u8, 0)) == "0");
+    }
+
+    #[test]
+    fn grouped() {
+        assert!(prepended(|s| s.prepend_int_grouped(0, ',')) == "0");
+        assert!(prepended(|s| s.prepend_int_grouped(999, ',')) == "999");
+        assert!(prepended(|s| s.prepend_int_grouped(1000, ',')) == "1,000");
+        assert!(prepended(|s| s.prepend_int_grouped(-1234567i32, ',')) == "-1,234,567");
+        assert!(
+            prepended(|s| s.prepend_int_grouped(u64::MAX, '\u{202f}'))
+                == "18\u{202f}446\u{202f}744\u{202f}073\u{202f}709\u{202f}551\u{202f}615"
+        );
+    }
+
+    #[test]
+    fn floats_match_display() {
+        for x in [
+            0.0,
+            -0.0,
+            1.0,
+            0.1,
+            -2.5e-7,
+            1e21,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ] {
+            assert!(prepended(|s| s.prepend_float(x)) == x.to_string());
+        }
+        for x in [0.1f32, f32::MAX, f32::MIN_POSITIVE / 3.0, -1.5f32] {
+            assert!(prepended(|s| s.prepend_float(x)) == x.to_string());
+        }
+    }
+
+    #[test]
+    fn writes_into_headroom() {
+        let mut s = FrontString::with_capacity(61);
+        s.push_str_front("]");
+        s.prepend_float(1.5);
+        s.prepend_int_grouped(-1000, ',');
+        s.prepend_int_padded(7u8, 3);
+        s.prepend_int_radix(255, 2);
+        s.prepend_int(i128::MIN);
+        assert!(s == "-17014118346046923173168730371588410572811111111007-1,0001.5]");
+        assert!(s.capacity() == 61);
+    }
+
+    #[test]
+    fn reserves_only_what_is_written() {
+        let mut s = FrontString::with_capacity(6);
+        s.prepend_int(7u128);
+        s.prepend_int(-42i64);
+        s.prepend_int_radix(2usize, 2);
+        assert!(s == "10-427");
+        assert!(s.capacity() == 6);
+
+        let mut s = FrontString::with_capacity(7);
+        s.prepend_int_grouped(-1000i128, ',');
+        s.prepend_int(0u128);
+        assert!(s == "0-1,000");
+        assert!(s.capacity() == 7);
+    }
+
+    #[test]
+    fn many_numbers_grow_geometrically() {
+        let mut s = FrontString::new();
+        let mut growths = 0;
+        for i in 0..10_000u32 {
+            let cap = s.capacity();
+            s.prepend_int(i % 10);
+            s.prepend_float(0.5);
+            if s.capacity() != cap {
+                growths += 1;
+            }
+        }
+        assert!(s.len() == 40_000);
+        assert!(s.starts_with("0.59"));
+        assert!(growths <= 16);
+    }
+}
@@ -0,0 +1,25 @@
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use alloc::vec::Vec;
+
+use crate::FrontVec;
+
+/// Picks how much spare capacity a generated value gets: none at all (so the
+/// capacity equals the length), less than the width of a multibyte `char`, or
+/// plenty. This way fuzzers hit both the grow and the no-grow paths.
+pub(crate) fn arbitrary_slack(u: &mut Unstructured<'_>) -> Result<usize> {
+    Ok(match u.choose_index(3)? {
+        0 => 0,
+        1 => u.int_in_range(1..=3)?,
+        _ => u.int_in_range(4..=64)?,
+    })
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for FrontVec<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let slack = arbitrary_slack(u)?;
+        let items: Vec<T> = u.arbitrary()?;
+        let mut v = FrontVec::with_capacity(items.len() + slack);
+        v.extend_front(items.into_iter());
+        Ok(v)
+    }
+}
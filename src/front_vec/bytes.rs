@@ -0,0 +1,152 @@
+use ::bytes::{buf::UninitSlice, Buf, BufMut, Bytes};
+
+use crate::FrontVec;
+
+/// Reading consumes bytes from the front of the `FrontVec`.
+impl Buf for FrontVec<u8> {
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        &self[..]
+    }
+
+    /// Removes `cnt` bytes from the front in O(1).
+    ///
+    /// # Panics
+    /// Panics if `cnt > self.remaining()`.
+    fn advance(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.len(),
+            "cannot advance past `remaining`: {cnt} > {}",
+            self.len()
+        );
+        // SAFETY: `u8` needs no dropping, and the last `len - cnt` bytes are
+        // still initialized.
+        unsafe {
+            self.set_len(self.len() - cnt);
+        }
+    }
+}
+
+impl From<FrontVec<u8>> for Bytes {
+    /// Wraps the `FrontVec` without copying its contents.
+    fn from(v: FrontVec<u8>) -> Self {
+        Bytes::from_owner(v)
+    }
+}
+
+impl FrontVec<u8> {
+    /// Returns a [`BufMut`] whose writes end up in front of the current
+    /// contents, in the order they were written, once it is dropped.
+    ///
+    /// The writes are staged at the start of the spare capacity, so the only
+    /// extra copy is a single `memmove` of the newly written bytes (plus two
+    /// more if the buffer has to grow).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontVec;
+    /// use bytes::BufMut;
+    ///
+    /// let mut packet = FrontVec::from(&b"payload"[..]);
+    /// let mut header = packet.prepend_buf_mut();
+    /// header.put_u16(0xABCD);
+    /// header.put_slice(b"hdr");
+    /// drop(header);
+    /// assert_eq!(&packet[..], b"\xAB\xCDhdrpayload");
+    /// ```
+    pub fn prepend_buf_mut(&mut self) -> PrependBufMut<'_> {
+        PrependBufMut {
+            vec: self,
+            written: 0,
+        }
+    }
+}
+
+/// A [`BufMut`] which prepends onto a `FrontVec<u8>`. See
+/// [`FrontVec::prepend_buf_mut`].
+pub struct PrependBufMut<'a> {
+    vec: &'a mut FrontVec<u8>,
+    /// The number of bytes written so far. They're stored at the very start of
+    /// the spare capacity.
+    written: usize,
+}
+
+impl PrependBufMut<'_> {
+    /// The number of bytes written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Moves the written bytes from the start of the spare capacity to just
+    /// in front of the `FrontVec`'s contents.
+    fn stage_to_front(&mut self) {
+        let spare = self.vec.spare_capacity_mut();
+        let end = spare.len();
+        spare.copy_within(0..self.written, end - self.written);
+    }
+
+    /// Grows the `FrontVec` while keeping the written bytes staged at the start
+    /// of its spare capacity.
+    fn grow(&mut self) {
+        let len = self.vec.len();
+
+        self.stage_to_front();
+        // SAFETY: The written bytes were just moved in front of the contents.
+        unsafe {
+            self.vec.set_len(len + self.written);
+        }
+
+        self.vec.reserve_front(usize::max(64, self.vec.capacity()));
+
+        // Forget the written bytes again. They now sit at the end of the spare
+        // capacity, so move them back to its start.
+        // SAFETY: `u8` needs no dropping, and `len <= capacity`.
+        unsafe {
+            self.vec.set_len(len);
+        }
+        let spare = self.vec.spare_capacity_mut();
+        let end = spare.len();
+        spare.copy_within(end - self.written..end, 0);
+    }
+}
+
+// SAFETY: `chunk_mut` only ever hands out the uninitialized bytes following the
+// written ones, and `advance_mut` refuses to count bytes beyond them.
+unsafe impl BufMut for PrependBufMut<'_> {
+    fn remaining_mut(&self) -> usize {
+        // Like `Vec<u8>`, this can grow until the allocation size limit.
+        isize::MAX as usize - self.vec.len() - self.written
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let available = self.vec.capacity() - self.vec.len() - self.written;
+        assert!(
+            cnt <= available,
+            "cannot advance past `chunk_mut`: {cnt} > {available}"
+        );
+        self.written += cnt;
+    }
+
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.vec.capacity() - self.vec.len() == self.written {
+            self.grow();
+        }
+
+        let spare = self.vec.spare_capacity_mut();
+        UninitSlice::uninit(&mut spare[self.written..])
+    }
+}
+
+impl Drop for PrependBufMut<'_> {
+    fn drop(&mut self) {
+        self.stage_to_front();
+        // SAFETY: The written bytes were just moved in front of the contents.
+        unsafe {
+            self.vec.set_len(self.vec.len() + self.written);
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::FrontVec;
+
+/// A read-only cursor over a [`FrontVec`].
+///
+/// Like `LinkedList`'s cursors, a cursor sits either on an element or on a
+/// "ghost" non-element between the back and the front, so moving past either
+/// end wraps around through the ghost.
+pub struct Cursor<'a, T> {
+    vec: &'a FrontVec<T>,
+    /// Equals `vec.len()` when on the ghost.
+    index: usize,
+}
+
+/// A cursor over a [`FrontVec`] which can edit the list around it.
+///
+/// Insertions before the cursor only shift the elements in front of it, into
+/// the `FrontVec`'s headroom, so editing near the front is cheap.
+///
+/// # Example
+///
+/// ```
+/// # use front_vec::FrontVec;
+/// let mut v = FrontVec::from(&["a", "MACRO", "d"]);
+/// let mut cursor = v.cursor_front_mut();
+/// cursor.move_next();
+/// cursor.splice_before(FrontVec::from(&["b", "c"]));
+/// assert_eq!(cursor.remove_current(), Some("MACRO"));
+/// assert_eq!(cursor.current(), Some(&mut "d"));
+/// assert_eq!(&v[..], &["a", "b", "c", "d"]);
+/// ```
+pub struct CursorMut<'a, T> {
+    vec: &'a mut FrontVec<T>,
+    /// Equals `vec.len()` when on the ghost.
+    index: usize,
+}
+
+impl<T> FrontVec<T> {
+    /// A cursor on the first element, or on the ghost if empty.
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor {
+            vec: self,
+            index: 0,
+        }
+    }
+
+    /// A cursor on the last element, or on the ghost if empty.
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor {
+            vec: self,
+            index: self.len().saturating_sub(1),
+        }
+    }
+
+    /// A mutable cursor on the first element, or on the ghost if empty.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            vec: self,
+            index: 0,
+        }
+    }
+
+    /// A mutable cursor on the last element, or on the ghost if empty.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let index = self.len().saturating_sub(1);
+        CursorMut { vec: self, index }
+    }
+}
+
+/// The index after `index` in a cycle through `0..len` and the ghost.
+fn next_index(index: usize, len: usize) -> usize {
+    if index == len {
+        0
+    } else {
+        index + 1
+    }
+}
+
+/// The index before `index` in a cycle through `0..len` and the ghost.
+fn prev_index(index: usize, len: usize) -> usize {
+    if index == 0 {
+        len
+    } else {
+        index - 1
+    }
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// The index of the current element, or `None` if on the ghost.
+    pub fn index(&self) -> Option<usize> {
+        (self.index < self.vec.len()).then_some(self.index)
+    }
+
+    pub fn move_next(&mut self) {
+        self.index = next_index(self.index, self.vec.len());
+    }
+
+    pub fn move_prev(&mut self) {
+        self.index = prev_index(self.index, self.vec.len());
+    }
+
+    /// The current element, or `None` if on the ghost.
+    pub fn current(&self) -> Option<&'a T> {
+        self.vec.get(self.index)
+    }
+
+    pub fn peek_next(&self) -> Option<&'a T> {
+        self.vec.get(next_index(self.index, self.vec.len()))
+    }
+
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        self.vec.get(prev_index(self.index, self.vec.len()))
+    }
+}
+
+impl<T> Clone for Cursor<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec,
+            index: self.index,
+        }
+    }
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// The index of the current element, or `None` if on the ghost.
+    pub fn index(&self) -> Option<usize> {
+        (self.index < self.vec.len()).then_some(self.index)
+    }
+
+    pub fn move_next(&mut self) {
+        self.index = next_index(self.index, self.vec.len());
+    }
+
+    pub fn move_prev(&mut self) {
+        self.index = prev_index(self.index, self.vec.len());
+    }
+
+    /// The current element, or `None` if on the ghost.
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.vec.get_mut(self.index)
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let index = next_index(self.index, self.vec.len());
+        self.vec.get_mut(index)
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let index = prev_index(self.index, self.vec.len());
+        self.vec.get_mut(index)
+    }
+
+    /// A read-only cursor at the same position, borrowing this one.
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        Cursor {
+            vec: self.vec,
+            index: self.index,
+        }
+    }
+
+    /// Inserts `val` before the current element, or at the back if on the
+    /// ghost. The cursor stays on the same element.
+    ///
+    /// Only the elements before the cursor are shifted.
+    pub fn insert_before(&mut self, val: T) {
+        self.vec.insert(self.index, val);
+        self.index += 1;
+    }
+
+    /// Inserts `val` after the current element, or at the front if on the
+    /// ghost. The cursor stays on the same element.
+    ///
+    /// The elements up to and including the current one are shifted.
+    pub fn insert_after(&mut self, val: T) {
+        if self.index == self.vec.len() {
+            self.vec.insert(0, val);
+            self.index += 1;
+        } else {
+            self.vec.insert(self.index + 1, val);
+        }
+    }
+
+    /// Removes and returns the current element, moving the cursor onto the
+    /// next one. Returns `None` (and does nothing) if on the ghost.
+    ///
+    /// Only the elements before the cursor are shifted.
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.index == self.vec.len() {
+            return None;
+        }
+        // The next element slides into `index`.
+        Some(self.vec.remove(self.index))
+    }
+
+    /// Moves all elements of `other` in before the current element, or at the
+    /// back if on the ghost. The cursor stays on the same element.
+    ///
+    /// Only the elements before the cursor are shifted, and if the headroom
+    /// isn't big enough, everything is copied into a bigger buffer just once.
+    pub fn splice_before(&mut self, other: FrontVec<T>) {
+        let n = other.len();
+        self.vec.insert_all(self.index, other);
+        self.index += n;
+    }
+}
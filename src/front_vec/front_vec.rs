@@ -1,8 +1,14 @@
-use std::{
-    alloc::{alloc, Layout},
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error, realloc, Layout},
+    boxed::Box,
+    vec::Vec,
+};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
     fmt,
     marker::PhantomData,
-    mem::{self, MaybeUninit},
+    mem::{self, ManuallyDrop, MaybeUninit},
     ops::{Deref, DerefMut, Index, IndexMut},
     ptr::Unique,
     slice::SliceIndex,
@@ -21,7 +27,17 @@ pub struct FrontVec<T> {
     _marker: PhantomData<T>,
 }
 
-fn alloc_buf<T>(len: usize) -> Unique<MaybeUninit<T>> {
+/// Buffers of at least this many bytes grow with `realloc` instead of with a
+/// fresh allocation.
+///
+/// The isolated doubling benchmarks in `benches/growth.rs` show no consistent
+/// winner between the two below 16 MiB, so they don't pin this value down. It
+/// is chosen for the `push_front_4m_u64*` benchmarks, a buffer growing
+/// repeatedly, where `realloc` from 128 KiB up is about twice as fast as never
+/// using it, and faster than using it at every size.
+const REALLOC_THRESHOLD_BYTES: usize = 128 * 1024;
+
+pub(crate) fn alloc_buf<T>(len: usize) -> Unique<MaybeUninit<T>> {
     assert_ne!(mem::size_of::<T>(), 0);
 
     if len == 0 {
@@ -29,17 +45,82 @@ fn alloc_buf<T>(len: usize) -> Unique<MaybeUninit<T>> {
     }
 
     let layout = Layout::array::<MaybeUninit<T>>(len).unwrap();
+    #[cfg(feature = "stats")]
+    crate::stats::record_allocation(layout.size());
+
+    #[cfg(all(feature = "mmap", target_os = "linux"))]
+    if is_mapped::<T>(len) {
+        return super::mmap::map(len);
+    }
+
     // SAFETY:
     // TODO[safety argument omitted]
     let ptr = unsafe { alloc(layout) as *mut MaybeUninit<T> };
     if ptr.is_null() {
-        std::alloc::handle_alloc_error(layout)
+        handle_alloc_error(layout)
     };
     // SAFETY:
     // TODO[safety argument omitted]
     unsafe { Unique::new_unchecked(ptr) }
 }
 
+/// Returns `true` if `alloc_buf(cap)` returns a page-mapped buffer rather than
+/// one from the global allocator. Only possible with the `mmap` feature.
+#[inline]
+pub(crate) fn is_mapped<T>(cap: usize) -> bool {
+    #[cfg(all(feature = "mmap", target_os = "linux"))]
+    return super::mmap::is_mapped::<T>(cap);
+
+    #[cfg(not(all(feature = "mmap", target_os = "linux")))]
+    {
+        let _ = (cap, mem::size_of::<T>());
+        false
+    }
+}
+
+/// Reports that a buffer of `old_cap` elements was replaced by one of
+/// `new_cap`, with `moved` elements copied over. Replacing the dangling
+/// buffer of capacity 0 doesn't count as growing.
+#[inline]
+pub(crate) fn record_growth<T>(old_cap: usize, new_cap: usize, moved: usize) {
+    let size = mem::size_of::<T>();
+    #[cfg(feature = "stats")]
+    if old_cap > 0 {
+        crate::stats::record_growth(crate::stats::GrowthEvent {
+            old_capacity_bytes: old_cap * size,
+            new_capacity_bytes: new_cap * size,
+            bytes_copied: moved * size,
+        });
+    }
+    #[cfg(not(feature = "stats"))]
+    let _ = (old_cap, new_cap, moved, size);
+}
+
+/// Frees a buffer returned by `alloc_buf(cap)`. Does nothing if `cap == 0`.
+///
+/// # Safety
+/// `buf` must have been returned by `alloc_buf(cap)`, it must not be used
+/// afterwards, and any initialized elements in it must already be dropped.
+pub(crate) unsafe fn dealloc_buf<T>(buf: Unique<MaybeUninit<T>>, cap: usize) {
+    if cap == 0 {
+        // No buffer has been allocated, so DO NOT deallocate it.
+        return;
+    }
+
+    #[cfg(all(feature = "mmap", target_os = "linux"))]
+    if is_mapped::<T>(cap) {
+        // SAFETY: `alloc_buf(cap)` mapped `buf`.
+        unsafe { super::mmap::unmap(buf, cap) };
+        return;
+    }
+
+    let layout = Layout::array::<MaybeUninit<T>>(cap).unwrap();
+    // SAFETY: `buf` was allocated by `alloc_buf` with this exact layout.
+    unsafe {
+        dealloc(buf.as_ptr() as *mut u8, layout);
+    }
+}
+
 impl<T> FrontVec<T> {
     pub fn new() -> Self {
         Self::with_capacity(0)
@@ -67,6 +148,77 @@ impl<T> FrontVec<T> {
         self.len == 0
     }
 
+    /// Grows the buffer to `new_cap`, using `grow_realloc` for buffers of at
+    /// least `REALLOC_THRESHOLD_BYTES` and `grow_no_realloc` otherwise.
+    ///
+    /// With the `mmap` feature, page-mapped buffers grow by remapping their
+    /// pages instead, which doesn't copy any elements.
+    fn grow(&mut self, new_cap: usize) {
+        #[cfg(all(feature = "mmap", target_os = "linux"))]
+        if is_mapped::<T>(self.cap) {
+            // SAFETY: `buf` was mapped by `alloc_buf(cap)` or a previous
+            // `remap`, and is replaced right away.
+            self.buf = unsafe { super::mmap::remap(self.buf, self.cap, new_cap) };
+            record_growth::<T>(self.cap, new_cap, 0);
+            self.cap = new_cap;
+            return;
+        }
+
+        let big = self.cap > 0 && self.cap * mem::size_of::<T>() >= REALLOC_THRESHOLD_BYTES;
+        if big && !is_mapped::<T>(new_cap) {
+            self.grow_realloc(new_cap);
+        } else {
+            self.grow_no_realloc(new_cap);
+        }
+    }
+
+    /// Grows the buffer to `new_cap` with `realloc`, then moves the elements
+    /// to the new back of the buffer.
+    ///
+    /// For large buffers the allocator can often extend the allocation in
+    /// place or remap its pages instead of copying them, but the elements
+    /// still have to be moved once since they live at the back.
+    ///
+    /// With the `mmap` feature, buffers that are (or would become)
+    /// page-mapped grow the same way `push_front` would grow them instead.
+    ///
+    /// # Panics
+    /// Panics if `new_cap < self.capacity()`.
+    pub fn grow_realloc(&mut self, new_cap: usize) {
+        assert!(new_cap >= self.cap, "cannot shrink with `grow_realloc`");
+        if is_mapped::<T>(self.cap) || is_mapped::<T>(new_cap) {
+            // `realloc` can't handle mapped buffers.
+            self.grow(new_cap);
+            return;
+        }
+        if self.cap == 0 {
+            // There's nothing to `realloc`.
+            self.grow_no_realloc(new_cap);
+            return;
+        }
+
+        let old_layout = Layout::array::<MaybeUninit<T>>(self.cap).unwrap();
+        let new_layout = Layout::array::<MaybeUninit<T>>(new_cap).unwrap();
+        // SAFETY: `buf` was allocated with `old_layout` (`cap > 0`), and
+        // `new_layout` has the same alignment and a valid, non-zero size.
+        let ptr = unsafe { realloc(self.buf.as_ptr() as *mut u8, old_layout, new_layout.size()) };
+        if ptr.is_null() {
+            handle_alloc_error(new_layout)
+        };
+        let ptr = ptr as *mut MaybeUninit<T>;
+
+        // SAFETY: `realloc` preserved the first `cap` elements' worth of
+        // bytes, so the elements are still at `old_cap - len`. The destination
+        // is in bounds of the new buffer, and `copy` allows overlap.
+        unsafe {
+            ptr.add(new_cap - self.len)
+                .copy_from(ptr.add(self.cap - self.len), self.len);
+            self.buf = Unique::new_unchecked(ptr);
+        }
+        record_growth::<T>(self.cap, new_cap, self.len);
+        self.cap = new_cap;
+    }
+
     pub fn double_no_realloc(&mut self) {
         self.grow_no_realloc(self.cap * 2);
     }
@@ -95,15 +247,23 @@ impl<T> FrontVec<T> {
                 front.copy_from_nonoverlapping(old_front, self.len);
             }
             // Deallocate old buffer.
-            let old_layout = Layout::array::<MaybeUninit<T>>(old_cap).unwrap();
             // SAFETY:
-            // TODO[safety argument omitted]
+            // The elements were moved out, and the old buffer is no longer
+            // reachable.
             unsafe {
-                std::alloc::dealloc(old_buf.as_ptr() as *mut u8, old_layout);
+                dealloc_buf(old_buf, old_cap);
             }
+            record_growth::<T>(old_cap, new_cap, self.len);
         }
     }
 
+    /// Decomposes into `(buf, cap, len)` without dropping anything. The caller
+    /// becomes responsible for the elements and for `dealloc_buf(buf, cap)`.
+    pub(crate) fn into_raw_parts(self) -> (Unique<MaybeUninit<T>>, usize, usize) {
+        let v = ManuallyDrop::new(self);
+        (v.buf, v.cap, v.len)
+    }
+
     fn front_internal_index(&self) -> usize {
         self.cap - self.len
     }
@@ -146,7 +306,7 @@ impl<T> FrontVec<T> {
             self.buf = alloc_buf(4);
             self.cap = 4;
         } else if self.len >= self.cap {
-            self.double_no_realloc();
+            self.grow(self.cap * 2);
         }
 
         // SAFETY:
@@ -177,7 +337,7 @@ impl<T> FrontVec<T> {
         if available_space >= extra_space_needed {
             false
         } else {
-            self.grow_no_realloc(self.capacity() + extra_space_needed);
+            self.grow(self.capacity() + extra_space_needed);
             true
         }
     }
@@ -209,7 +369,7 @@ impl<T> FrontVec<T> {
         // Sketch of safety argument:
         // `slice::from_raw_parts_mut(self.spare_capacity_raw_parts_mut())`
         // is valid almost by definition.
-        unsafe { std::slice::from_raw_parts_mut(data, len) }
+        unsafe { core::slice::from_raw_parts_mut(data, len) }
     }
 
     /// # Safety
@@ -279,12 +439,490 @@ impl<T> FrontVec<T> {
                 // The pointer `ptr` is immediately discarded at the end of the loop body. It
                 // won't be used again after the `drop_in_place`.
                 unsafe {
-                    std::ptr::drop_in_place(item as *mut _);
+                    core::ptr::drop_in_place(item as *mut _);
                 }
             }
         }
         self.len = new_len;
     }
+
+    /// Like `reserve_front`, but grows geometrically like `push_front` so that
+    /// repeatedly prepending a few elements at a time stays amortized O(1).
+    pub(crate) fn reserve_front_amortized(&mut self, extra_space_needed: usize) {
+        let available_space = self.capacity() - self.len();
+
+        if available_space < extra_space_needed {
+            let new_cap = usize::max(self.len + extra_space_needed, 2 * self.cap);
+            self.grow(usize::max(4, new_cap));
+        }
+    }
+
+    /// Returns the first `n` elements, or `None` if there are fewer than `n`.
+    pub fn peek_front_n(&self, n: usize) -> Option<&[T]> {
+        self.get(..n)
+    }
+
+    /// Removes the first `n` elements and returns them in a new `FrontVec`.
+    ///
+    /// # Panics
+    /// Panics if `n > self.len()`.
+    #[track_caller]
+    pub fn pop_front_n(&mut self, n: usize) -> FrontVec<T> {
+        assert!(n <= self.len, "cannot pop {n} elements of {}", self.len);
+
+        let mut popped = FrontVec::with_capacity(n);
+        // SAFETY: The first `n` elements are initialized, and `popped` has
+        // room for exactly `n`. They're forgotten by `self` right after, so
+        // they end up with exactly one owner.
+        unsafe {
+            popped
+                .buf
+                .as_ptr()
+                .copy_from_nonoverlapping(self.front_ptr(), n);
+            popped.set_len(n);
+            self.set_len(self.len - n);
+        }
+        popped
+    }
+
+    /// Removes the first `N` elements and returns them as an array, or
+    /// returns `None` (leaving `self` untouched) if there are fewer than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontVec;
+    /// let mut stack = FrontVec::from(&[2, 3, 4]);
+    /// let [a, b] = stack.pop_front_array().unwrap();
+    /// stack.push_front(a * b);
+    /// assert_eq!(stack, FrontVec::from(&[6, 4]));
+    /// ```
+    pub fn pop_front_array<const N: usize>(&mut self) -> Option<[T; N]> {
+        if N > self.len {
+            return None;
+        }
+
+        // SAFETY: The first `N` elements are initialized and contiguous, and
+        // `[T; N]` has the same alignment as `T`. They're forgotten right
+        // after being read.
+        unsafe {
+            let array = self.front_ptr().cast::<[T; N]>().read();
+            self.set_len(self.len - N);
+            Some(array)
+        }
+    }
+
+    /// Prepends all elements of `array`, keeping their order.
+    pub fn push_front_array<const N: usize>(&mut self, array: [T; N]) {
+        self.reserve_front_amortized(N);
+
+        let spare = self.spare_capacity_mut();
+        let begin = spare.len() - N;
+        // SAFETY: `spare[begin..]` is in bounds and uninitialized, and
+        // `[T; N]` has the same alignment as `T`.
+        unsafe {
+            spare.as_mut_ptr().add(begin).cast::<[T; N]>().write(array);
+            self.set_len(self.len + N);
+        }
+    }
+
+    /// Drops the first `n` elements and prepends `val` in their place, like a
+    /// stack machine instruction which pops `n` operands and pushes a result.
+    ///
+    /// # Panics
+    /// Panics if `n > self.len()`.
+    #[track_caller]
+    pub fn replace_front(&mut self, n: usize, val: T) {
+        assert!(n <= self.len, "cannot replace {n} elements of {}", self.len);
+
+        let front = self.front_mut().as_mut_ptr();
+        // SAFETY: The first `n` elements are initialized. They're forgotten
+        // before being dropped, so a panicking destructor can't cause a
+        // double drop.
+        unsafe {
+            self.set_len(self.len - n);
+            core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(front, n));
+        }
+
+        if n == 0 {
+            self.push_front(val);
+        } else {
+            // SAFETY: Dropping the `n > 0` elements freed up at least one slot.
+            let slot = unsafe { self.before_front_mut() };
+            slot.write(val);
+            self.len += 1;
+        }
+    }
+
+    /// Moves all elements of `other` onto the front of `self`, leaving `other`
+    /// empty.
+    ///
+    /// If `self` lacks the headroom but `other`'s buffer is big enough for
+    /// both, the two buffers are swapped instead of growing `self`.
+    pub fn prepend(&mut self, other: &mut Self) {
+        let n = other.len;
+        let total = self.len + n;
+
+        if self.cap - self.len < n && other.cap >= total {
+            // SAFETY: `other`'s buffer has room for `total` elements. Its own
+            // elements are shifted towards the front (possibly overlapping),
+            // then `self`'s are copied in behind them. Each element ends up
+            // owned by exactly one vec once the lengths are updated.
+            unsafe {
+                let dst = other.buf.as_ptr().add(other.cap - total);
+                dst.copy_from(other.front_ptr(), n);
+                dst.add(n)
+                    .copy_from_nonoverlapping(self.front_ptr(), self.len);
+            }
+            self.len = 0;
+            other.len = total;
+            mem::swap(self, other);
+            return;
+        }
+
+        self.reserve_front_amortized(n);
+        // SAFETY: There's room for `n` elements in front of `self`, and
+        // `other` forgets them right after.
+        unsafe {
+            self.buf
+                .as_ptr()
+                .add(self.front_internal_index() - n)
+                .copy_from_nonoverlapping(other.front_ptr(), n);
+        }
+        self.len = total;
+        other.len = 0;
+    }
+
+    /// Moves all elements of `other` onto the back of `self`, leaving `other`
+    /// empty.
+    ///
+    /// If `other` has enough headroom for `self`'s elements, they're copied in
+    /// front of `other`'s and the two buffers are swapped. Otherwise `self`'s
+    /// elements are shifted towards the front of its buffer to make room.
+    pub fn append(&mut self, other: &mut Self) {
+        let n = other.len;
+        if n == 0 {
+            return;
+        }
+        let total = self.len + n;
+
+        if other.cap >= total {
+            // SAFETY: There's room for `self.len` elements in front of
+            // `other`, and `self` forgets them right after.
+            unsafe {
+                other
+                    .buf
+                    .as_ptr()
+                    .add(other.cap - total)
+                    .copy_from_nonoverlapping(self.front_ptr(), self.len);
+            }
+            self.len = 0;
+            other.len = total;
+            mem::swap(self, other);
+            return;
+        }
+
+        self.reserve_front_amortized(n);
+        // SAFETY: `self`'s buffer has room for `total` elements. Its own
+        // elements are shifted towards the front (possibly overlapping), then
+        // `other`'s are copied in behind them and forgotten by `other`.
+        unsafe {
+            let dst = self.buf.as_ptr().add(self.cap - total);
+            dst.copy_from(self.front_ptr(), self.len);
+            dst.add(self.len)
+                .copy_from_nonoverlapping(other.front_ptr(), n);
+        }
+        self.len = total;
+        other.len = 0;
+    }
+
+    /// Splits the `FrontVec` in two at `at`. `self` keeps the elements
+    /// `[0, at)` and the elements `[at, len)` are returned.
+    ///
+    /// `self`'s capacity is unchanged. Prefer [`split_off_front`] when `self`
+    /// should keep the back part, which moves fewer elements.
+    ///
+    /// [`split_off_front`]: FrontVec::split_off_front
+    ///
+    /// # Panics
+    /// Panics if `at > self.len()`.
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> FrontVec<T> {
+        assert!(at <= self.len, "cannot split {} elements at {at}", self.len);
+
+        let n = self.len - at;
+        let mut back = FrontVec::with_capacity(n);
+        // SAFETY: The elements `[at, len)` are moved into `back`, which has
+        // room for exactly `n`. Then `[0, at)` is shifted to the back of
+        // `self`'s buffer (possibly overlapping), so it stays contiguous.
+        unsafe {
+            let front = self.front_ptr();
+            back.buf.as_ptr().copy_from_nonoverlapping(front.add(at), n);
+            back.set_len(n);
+            self.buf.as_ptr().add(self.cap - at).copy_from(front, at);
+            self.set_len(at);
+        }
+        back
+    }
+
+    /// Splits the `FrontVec` in two at `at`. The elements `[0, at)` are
+    /// returned and `self` keeps `[at, len)` in place.
+    ///
+    /// # Panics
+    /// Panics if `at > self.len()`.
+    #[track_caller]
+    pub fn split_off_front(&mut self, at: usize) -> FrontVec<T> {
+        self.pop_front_n(at)
+    }
+
+    /// Inserts `val` at `index`, shifting the elements before it one slot
+    /// towards the front, which is cheap when `index` is small.
+    ///
+    /// If there's no headroom, the elements are copied into a bigger buffer
+    /// with `val` placed in between, so nothing is shifted twice.
+    ///
+    /// # Panics
+    /// Panics if `index > self.len()`.
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, val: T) {
+        assert!(
+            index <= self.len,
+            "insertion index (is {index}) should be <= len (is {})",
+            self.len
+        );
+
+        if self.len < self.cap {
+            // SAFETY: There's a free slot in front of the elements. The
+            // prefix `[0, index)` is shifted into it (possibly overlapping),
+            // which frees up the slot for `val`.
+            unsafe {
+                let front = self.buf.as_ptr().add(self.front_internal_index());
+                front.sub(1).copy_from(front, index);
+                front.sub(1).add(index).write(MaybeUninit::new(val));
+            }
+            self.len += 1;
+            return;
+        }
+
+        let new_cap = usize::max(4, 2 * self.cap);
+        let new_buf = alloc_buf::<T>(new_cap);
+        // SAFETY: The new buffer has room for `len + 1` elements. The old
+        // buffer's elements are moved out and it's freed without dropping
+        // them.
+        unsafe {
+            let old_front = self.front_ptr();
+            let new_front = new_buf.as_ptr().add(new_cap - self.len - 1);
+            new_front.copy_from_nonoverlapping(old_front, index);
+            new_front.add(index).write(MaybeUninit::new(val));
+            new_front
+                .add(index + 1)
+                .copy_from_nonoverlapping(old_front.add(index), self.len - index);
+            dealloc_buf(mem::replace(&mut self.buf, new_buf), self.cap);
+        }
+        record_growth::<T>(self.cap, new_cap, self.len);
+        self.cap = new_cap;
+        self.len += 1;
+    }
+
+    /// Moves all elements of `other` in before `index`. Like `insert`, only
+    /// the elements before `index` are shifted if there's enough headroom.
+    pub(crate) fn insert_all(&mut self, index: usize, mut other: FrontVec<T>) {
+        assert!(index <= self.len);
+        let n = other.len;
+
+        if self.cap - self.len >= n {
+            // SAFETY: There are `n` free slots in front of the elements. The
+            // prefix `[0, index)` is shifted into them (possibly overlapping),
+            // which frees up `n` slots for `other`'s elements. `other` forgets
+            // them right after.
+            unsafe {
+                let front = self.buf.as_ptr().add(self.front_internal_index());
+                front.sub(n).copy_from(front, index);
+                front
+                    .sub(n)
+                    .add(index)
+                    .copy_from_nonoverlapping(other.front_ptr(), n);
+            }
+            self.len += n;
+            other.len = 0;
+            return;
+        }
+
+        let new_cap = usize::max(self.len + n, 2 * self.cap);
+        let new_buf = alloc_buf::<T>(new_cap);
+        // SAFETY: The new buffer has room for `len + n` elements. The elements
+        // of both old buffers are moved out, and `self`'s is freed without
+        // dropping them. `other` forgets its elements right after.
+        unsafe {
+            let old_front = self.front_ptr();
+            let new_front = new_buf.as_ptr().add(new_cap - self.len - n);
+            new_front.copy_from_nonoverlapping(old_front, index);
+            new_front
+                .add(index)
+                .copy_from_nonoverlapping(other.front_ptr(), n);
+            new_front
+                .add(index + n)
+                .copy_from_nonoverlapping(old_front.add(index), self.len - index);
+            dealloc_buf(mem::replace(&mut self.buf, new_buf), self.cap);
+        }
+        record_growth::<T>(self.cap, new_cap, self.len);
+        self.cap = new_cap;
+        self.len += n;
+        other.len = 0;
+    }
+
+    /// Removes and returns the element at `index`, shifting the elements
+    /// before it one slot towards the back.
+    ///
+    /// # Panics
+    /// Panics if `index >= self.len()`.
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len,
+            "removal index (is {index}) should be < len (is {})",
+            self.len
+        );
+
+        // SAFETY: The element at `index` is initialized and is read out
+        // before being overwritten by the shifted prefix (possibly
+        // overlapping), which leaves the front slot unused.
+        unsafe {
+            let front = self.buf.as_ptr().add(self.front_internal_index());
+            let val = front.add(index).read().assume_init();
+            front.add(1).copy_from(front, index);
+            self.len -= 1;
+            val
+        }
+    }
+
+    /// Inserts `val` into a `FrontVec` sorted according to `compare`, before
+    /// any equal elements, and returns its index. See [`insert`] for the cost.
+    ///
+    /// [`insert`]: FrontVec::insert
+    pub fn insert_sorted_by<F>(&mut self, val: T, mut compare: F) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let index = self.partition_point(|x| compare(x, &val) == Ordering::Less);
+        self.insert(index, val);
+        index
+    }
+
+    /// Inserts `val` into a `FrontVec` sorted by the key `f` extracts, before
+    /// any elements with an equal key, and returns its index.
+    pub fn insert_sorted_by_key<K, F>(&mut self, val: T, mut f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let key = f(&val);
+        let index = self.partition_point(|x| f(x) < key);
+        self.insert(index, val);
+        index
+    }
+}
+
+impl<T: Ord> FrontVec<T> {
+    /// Inserts `val` into a sorted `FrontVec`, before any equal elements, and
+    /// returns its index.
+    ///
+    /// Only the elements before the insertion point are shifted (into the
+    /// headroom), so this is cheapest for values that sort near the front.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontVec;
+    /// let mut v = FrontVec::from(&[1, 3, 5]);
+    /// assert_eq!(v.insert_sorted(4), 2);
+    /// assert_eq!(v.insert_sorted(0), 0);
+    /// assert_eq!(&v[..], &[0, 1, 3, 4, 5]);
+    /// ```
+    pub fn insert_sorted(&mut self, val: T) -> usize {
+        self.insert_sorted_by(val, T::cmp)
+    }
+
+    /// Inserts `val` into a sorted `FrontVec` unless an equal element is
+    /// already present.
+    ///
+    /// Returns `Ok` with the index `val` was inserted at, or `Err` with the
+    /// index of the equal element (in which case `val` is dropped).
+    pub fn insert_sorted_dedup(&mut self, val: T) -> Result<usize, usize> {
+        match self.binary_search(&val) {
+            Ok(index) => Err(index),
+            Err(index) => {
+                self.insert(index, val);
+                Ok(index)
+            }
+        }
+    }
+
+    /// Removes and returns an element equal to `key` from a sorted
+    /// `FrontVec`, or returns `None` if there is none.
+    pub fn remove_sorted<Q>(&mut self, key: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.binary_search_by(|x| x.borrow().cmp(key)).ok()?;
+        Some(self.remove(index))
+    }
+}
+
+impl<T: Clone> FrontVec<T> {
+    /// Prepends clones of the first `n` elements, so `[a, b, c]` becomes
+    /// `[a, b, a, b, c]` for `n == 2`.
+    ///
+    /// # Panics
+    /// Panics if `n > self.len()`.
+    #[track_caller]
+    pub fn dup_front(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "cannot duplicate {n} elements of {}",
+            self.len
+        );
+
+        self.reserve_front_amortized(n);
+
+        let src = self.front_ptr() as *const T;
+        for i in (0..n).rev() {
+            // SAFETY: `src` stays valid since nothing reallocates below, and
+            // `src.add(i)` is one of the original first `n` elements. There is
+            // room in front of `self` for each of the `n` clones. `len` is
+            // bumped after each write, so a panicking `clone` leaks nothing.
+            unsafe {
+                let item = (*src.add(i)).clone();
+                self.before_front_mut().write(item);
+            }
+            self.len += 1;
+        }
+    }
+}
+
+impl<T: Copy> FrontVec<T> {
+    /// Copies all elements of `slice` onto the front of the `FrontVec`,
+    /// reallocating at most once.
+    pub fn extend_front_from_slice(&mut self, slice: &[T]) {
+        self.reserve_front(slice.len());
+
+        let spare = self.spare_capacity_mut();
+        let begin = spare.len() - slice.len();
+        // SAFETY:
+        // * `reserve_front` guarantees `spare[begin..]` is in bounds.
+        // * `slice` can't overlap the spare capacity, which nothing else can
+        //   reference while `self` is mutably borrowed.
+        // * `T: Copy`, so duplicating the elements is fine.
+        unsafe {
+            spare
+                .as_mut_ptr()
+                .add(begin)
+                .cast::<T>()
+                .copy_from_nonoverlapping(slice.as_ptr(), slice.len());
+            self.set_len(self.len + slice.len());
+        }
+    }
 }
 
 impl<T> AsMut<[T]> for FrontVec<T> {
@@ -292,7 +930,7 @@ impl<T> AsMut<[T]> for FrontVec<T> {
         let front = self.front_mut().as_mut_ptr();
         // SAFETY:
         // TODO[safety argument omitted]
-        unsafe { std::slice::from_raw_parts_mut(front, self.len) }
+        unsafe { core::slice::from_raw_parts_mut(front, self.len) }
     }
 }
 
@@ -301,7 +939,7 @@ impl<T> AsRef<[T]> for FrontVec<T> {
         let front = self.front_ptr();
         // SAFETY:
         // TODO[safety argument omitted]
-        let slice = unsafe { std::slice::from_raw_parts(front, self.len) };
+        let slice = unsafe { core::slice::from_raw_parts(front, self.len) };
         unsafe { MaybeUninit::slice_assume_init_ref(slice) }
     }
 }
@@ -359,22 +997,15 @@ impl<T> Drop for FrontVec<T> {
                 // The pointer `ptr` is immediately discarded at the end of the loop body. It
                 // won't be used again after the `drop_in_place`.
                 unsafe {
-                    std::ptr::drop_in_place(item as *mut _);
+                    core::ptr::drop_in_place(item as *mut _);
                 }
             }
         }
 
-        if self.cap == 0 {
-            // No buffer has been allocated, so DO NOT deallocate it.
-            return;
-        }
-
-        let buf = self.buf.as_ptr() as *mut u8;
-        let layout = Layout::array::<T>(self.cap).unwrap();
         // SAFETY:
-        // TODO[safety argument omitted]
+        // The elements were just dropped, and `self` is never used again.
         unsafe {
-            std::alloc::dealloc(buf, layout);
+            dealloc_buf(self.buf, self.cap);
         }
     }
 }
@@ -436,9 +1067,35 @@ impl<T: Clone, const N: usize> From<&[T; N]> for FrontVec<T> {
     }
 }
 
+impl<T, const N: usize> From<[T; N]> for FrontVec<T> {
+    /// Moves the elements in without cloning, allocating exactly `N` slots.
+    fn from(array: [T; N]) -> Self {
+        let mut v = FrontVec::with_capacity(N);
+        v.push_front_array(array);
+        v
+    }
+}
+
 impl<T> From<Vec<T>> for FrontVec<T> {
     /// Note: Any extra capacity is dropped.
-    fn from(v: Vec<T>) -> Self {
+    fn from(mut v: Vec<T>) -> Self {
+        if is_mapped::<T>(v.len()) {
+            // A buffer this big has to be page-mapped, so it can't be adopted.
+            let len = v.len();
+            let mut fv = FrontVec::with_capacity(len);
+            // SAFETY: `fv` has room for exactly `len` elements, which `v`
+            // forgets right after.
+            unsafe {
+                fv.buf
+                    .as_ptr()
+                    .cast::<T>()
+                    .copy_from_nonoverlapping(v.as_ptr(), len);
+                v.set_len(0);
+                fv.set_len(len);
+            }
+            return fv;
+        }
+
         let bs = v.into_boxed_slice();
         let len = bs.len();
         let cap = len;
@@ -452,6 +1109,45 @@ impl<T> From<Vec<T>> for FrontVec<T> {
     }
 }
 
+impl<T> From<FrontVec<T>> for Vec<T> {
+    /// Moves the elements to the start of the buffer and hands the allocation
+    /// over to the `Vec`, so no new buffer is allocated.
+    fn from(v: FrontVec<T>) -> Self {
+        if is_mapped::<T>(v.cap) {
+            // A page-mapped buffer can't be handed over to the `Vec`.
+            let mut v = v;
+            let mut out = Vec::<T>::with_capacity(v.len);
+            // SAFETY: `out` has room for `len` elements, which `v` forgets
+            // right after, before unmapping its buffer.
+            unsafe {
+                out.as_mut_ptr()
+                    .copy_from_nonoverlapping(v.front_ptr().cast::<T>(), v.len);
+                out.set_len(v.len);
+                v.set_len(0);
+            }
+            return out;
+        }
+
+        let v = ManuallyDrop::new(v);
+
+        if v.cap == 0 {
+            return Vec::new();
+        }
+
+        let buf = v.buf.as_ptr() as *mut T;
+        // SAFETY:
+        // The initialized elements occupy `cap - len..cap`. `ptr::copy` allows
+        // overlapping regions, so shifting them down to `0..len` is sound.
+        // The buffer was allocated by the global allocator with the layout of
+        // `[T; cap]`, which is exactly what `Vec::from_raw_parts` expects.
+        // Since `v` is never dropped, ownership of the buffer is transferred.
+        unsafe {
+            core::ptr::copy(buf.add(v.cap - v.len), buf, v.len);
+            Vec::from_raw_parts(buf, v.len, v.cap)
+        }
+    }
+}
+
 impl<T: Clone> Clone for FrontVec<T> {
     fn clone(&self) -> Self {
         let mut new = Self::with_capacity(self.cap);
@@ -0,0 +1,48 @@
+use std::io::{self, BufRead, Read};
+
+use crate::FrontVec;
+
+/// Reading consumes bytes from the front of the `FrontVec`.
+impl Read for FrontVec<u8> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = usize::min(buf.len(), self.len());
+        buf[..n].copy_from_slice(&self[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        if buf.len() > self.len() {
+            // Like `&[u8]`, consume everything before reporting the error.
+            self.consume(self.len());
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        self.read(buf).map(drop)
+    }
+
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let n = self.len();
+        buf.extend_from_slice(self);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl BufRead for FrontVec<u8> {
+    /// Returns all remaining bytes.
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(&self[..])
+    }
+
+    /// Removes `amt` bytes from the front in O(1). The capacity they occupied
+    /// becomes available to `push_front` again.
+    fn consume(&mut self, amt: usize) {
+        let amt = usize::min(amt, self.len());
+        // SAFETY: `u8` needs no dropping, and the last `len - amt` bytes are
+        // still initialized.
+        unsafe {
+            self.set_len(self.len() - amt);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Page-mapped storage for large buffers, which can grow without copying.
+//!
+//! A mapped buffer is placed at the very end of its mapping:
+//! ```ignore
+//! base                                          base + map_len
+//! |<- < 1 page ->|<------------ cap * size_of::<T>() ------------>|
+//! [   unused     | ?, ?, ?, ?, ?, ?, ?, e1, e2, e3, e4, e5, e6, e7 ]
+//! ```
+//! Since a `FrontVec`'s elements live at the back of its buffer, they also
+//! live at the back of the mapping. So growing just moves the old pages to the
+//! tail of a bigger mapping with `mremap`, and no element is copied.
+//!
+//! Whether a buffer is mapped only depends on its capacity (see
+//! [`is_mapped`]), so `dealloc_buf` can tell which kind of buffer it's
+//! freeing.
+
+use alloc::alloc::{handle_alloc_error, Layout};
+use core::{
+    mem::{self, MaybeUninit},
+    ptr::{self, Unique},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Buffers of at least this many bytes are mapped.
+pub(crate) const MMAP_THRESHOLD_BYTES: usize = 1024 * 1024;
+
+fn page_size() -> usize {
+    static PAGE_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+    let mut size = PAGE_SIZE.load(Ordering::Relaxed);
+    if size == 0 {
+        // SAFETY: `sysconf` has no preconditions.
+        size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        PAGE_SIZE.store(size, Ordering::Relaxed);
+    }
+    size
+}
+
+/// Returns `true` if a buffer of `cap` elements is (or would be) mapped.
+///
+/// Types aligned to more than a page can't be placed at the end of a mapping,
+/// so they always use the global allocator.
+pub(crate) fn is_mapped<T>(cap: usize) -> bool {
+    mem::align_of::<T>() <= page_size()
+        && cap.saturating_mul(mem::size_of::<T>()) >= MMAP_THRESHOLD_BYTES
+}
+
+/// The length in bytes of the mapping holding a buffer of `cap` elements.
+fn map_len<T>(cap: usize) -> usize {
+    let bytes = Layout::array::<T>(cap).unwrap().size();
+    bytes.next_multiple_of(page_size())
+}
+
+/// The start of the mapping holding `buf`. The buffer starts less than a page
+/// after it.
+fn map_base<T>(buf: Unique<MaybeUninit<T>>) -> *mut libc::c_void {
+    let addr = buf.as_ptr() as usize;
+    buf.as_ptr()
+        .cast::<u8>()
+        .wrapping_sub(addr % page_size())
+        .cast()
+}
+
+/// The buffer of `cap` elements at the end of the mapping at `base`.
+///
+/// # Safety
+/// `base` must be a mapping of `map_len::<T>(cap)` bytes.
+unsafe fn buf_in_mapping<T>(base: *mut libc::c_void, cap: usize) -> Unique<MaybeUninit<T>> {
+    let offset = map_len::<T>(cap) - cap * mem::size_of::<T>();
+    // SAFETY: `offset` is in bounds of the mapping and, since the mapping's
+    // length is a multiple of the page size (which is a multiple of `T`'s
+    // alignment), so is the buffer's start.
+    unsafe { Unique::new_unchecked(base.cast::<u8>().add(offset).cast()) }
+}
+
+/// Maps `len` fresh, zeroed bytes.
+fn mmap_anonymous(len: usize) -> *mut libc::c_void {
+    // SAFETY: Requesting a new private anonymous mapping doesn't affect any
+    // existing memory.
+    let base = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            len,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        )
+    };
+    if base == libc::MAP_FAILED {
+        handle_alloc_error(Layout::from_size_align(len, page_size()).unwrap());
+    }
+    base
+}
+
+/// Maps a buffer of `cap` elements. `is_mapped::<T>(cap)` must be `true`.
+pub(crate) fn map<T>(cap: usize) -> Unique<MaybeUninit<T>> {
+    debug_assert!(is_mapped::<T>(cap));
+    let base = mmap_anonymous(map_len::<T>(cap));
+    // SAFETY: `base` was just mapped with the right length.
+    unsafe { buf_in_mapping(base, cap) }
+}
+
+/// Unmaps a buffer returned by `map(cap)` or `remap(.., cap)`.
+///
+/// # Safety
+/// `buf` must not be used afterwards, and any initialized elements in it must
+/// already be dropped.
+pub(crate) unsafe fn unmap<T>(buf: Unique<MaybeUninit<T>>, cap: usize) {
+    // SAFETY: `buf` lies at the end of a mapping of exactly this length.
+    unsafe {
+        libc::munmap(map_base(buf), map_len::<T>(cap));
+    }
+}
+
+/// Grows a mapped buffer of `old_cap` elements to `new_cap`, keeping the
+/// contents of its back in place relative to the end of the buffer. Only page
+/// tables are updated, no element is copied.
+///
+/// # Safety
+/// `buf` must have been returned by `map(old_cap)` or `remap(.., old_cap)`,
+/// `new_cap >= old_cap`, and `buf` must not be used afterwards.
+pub(crate) unsafe fn remap<T>(
+    buf: Unique<MaybeUninit<T>>,
+    old_cap: usize,
+    new_cap: usize,
+) -> Unique<MaybeUninit<T>> {
+    let old_base = map_base(buf);
+    let old_len = map_len::<T>(old_cap);
+    let new_len = map_len::<T>(new_cap);
+
+    if new_len == old_len {
+        // The bigger buffer still fits in the same mapping.
+        // SAFETY: `old_base` is a mapping of `new_len` bytes.
+        return unsafe { buf_in_mapping(old_base, new_cap) };
+    }
+
+    // Reserve the new mapping, then move the old pages onto its tail, which
+    // unmaps whatever was there (and unmaps the old mapping).
+    let new_base = mmap_anonymous(new_len);
+    // SAFETY: The destination is the last `old_len` bytes of the mapping we
+    // just created, and `MREMAP_FIXED` atomically replaces them.
+    let moved = unsafe {
+        libc::mremap(
+            old_base,
+            old_len,
+            old_len,
+            libc::MREMAP_MAYMOVE | libc::MREMAP_FIXED,
+            new_base.cast::<u8>().add(new_len - old_len),
+        )
+    };
+    if moved == libc::MAP_FAILED {
+        handle_alloc_error(Layout::from_size_align(new_len, page_size()).unwrap());
+    }
+
+    // SAFETY: `new_base` is a mapping of `new_len` bytes.
+    unsafe { buf_in_mapping(new_base, new_cap) }
+}
@@ -1,7 +1,27 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod cursor;
 mod front_vec;
+#[cfg(feature = "std")]
+mod io;
 pub mod iter;
+#[cfg(all(feature = "mmap", target_os = "linux"))]
+mod mmap;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rayon")]
+mod rayon;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "arbitrary")]
+pub(crate) use self::arbitrary::arbitrary_slack;
+#[cfg(feature = "bytes")]
+pub use self::bytes::PrependBufMut;
+pub use self::cursor::{Cursor, CursorMut};
+#[cfg(feature = "proptest")]
+pub(crate) use self::proptest::slack as proptest_slack;
 pub use crate::front_vec::front_vec::*;
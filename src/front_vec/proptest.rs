@@ -0,0 +1,26 @@
+use ::proptest::{
+    collection::{vec, SizeRange},
+    prelude::*,
+};
+
+use crate::FrontVec;
+
+/// Generates how much spare capacity a value gets. See `arbitrary_slack`.
+pub(crate) fn slack() -> impl Strategy<Value = usize> {
+    prop_oneof![Just(0), 1..=3usize, 4..=64usize]
+}
+
+impl<T: Arbitrary + 'static> Arbitrary for FrontVec<T> {
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+        (vec(any_with::<T>(params), size), slack())
+            .prop_map(|(items, slack)| {
+                let mut v = FrontVec::with_capacity(items.len() + slack);
+                v.extend_front(items.into_iter());
+                v
+            })
+            .boxed()
+    }
+}
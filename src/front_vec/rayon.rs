@@ -0,0 +1,95 @@
+use ::rayon::prelude::*;
+use alloc::vec::Vec;
+
+use crate::FrontVec;
+
+/// Moves the elements to the start of the buffer (see `From<FrontVec<T>> for
+/// Vec<T>`) and iterates over them like a `Vec`.
+impl<T: Send> IntoParallelIterator for FrontVec<T> {
+    type Item = T;
+    type Iter = ::rayon::vec::IntoIter<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        Vec::from(self).into_par_iter()
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for &'a FrontVec<T> {
+    type Item = &'a T;
+    type Iter = ::rayon::slice::Iter<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self[..].par_iter()
+    }
+}
+
+impl<'a, T: Send> IntoParallelIterator for &'a mut FrontVec<T> {
+    type Item = &'a mut T;
+    type Iter = ::rayon::slice::IterMut<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self[..].par_iter_mut()
+    }
+}
+
+/// Collects into a `Vec`, then adopts its buffer without any headroom.
+impl<T: Send> FromParallelIterator<T> for FrontVec<T> {
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(items: I) -> Self {
+        Vec::from_par_iter(items).into()
+    }
+}
+
+impl<T: Send> FrontVec<T> {
+    /// Prepends all of `items`, keeping their order, like `extend_front`.
+    ///
+    /// The headroom is reserved up front (reallocating at most once), then
+    /// split into disjoint regions which rayon fills in parallel, each item
+    /// being written straight into its final slot.
+    ///
+    /// # Panics
+    /// Panics if `items` yields fewer items than its `len()`. The items already
+    /// written are then leaked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::FrontVec;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut v = FrontVec::from([100, 101]);
+    /// v.par_extend_front((0..100).into_par_iter().map(|i| i * i));
+    /// assert_eq!(v.len(), 102);
+    /// assert_eq!(v[..3], [0, 1, 4]);
+    /// assert_eq!(v[98..], [9604, 9801, 100, 101]);
+    /// ```
+    pub fn par_extend_front<I>(&mut self, items: I)
+    where
+        I: IntoParallelIterator<Item = T>,
+        I::Iter: IndexedParallelIterator,
+    {
+        let items = items.into_par_iter();
+        let n = items.len();
+        self.reserve_front(n);
+
+        let spare = self.spare_capacity_mut();
+        let begin = spare.len() - n;
+        let written: usize = spare[begin..]
+            .par_iter_mut()
+            .zip(items)
+            .map(|(slot, item)| {
+                slot.write(item);
+                1
+            })
+            .sum();
+        assert_eq!(
+            written, n,
+            "parallel iterator yielded fewer items than its `len()`"
+        );
+
+        // SAFETY: `reserve_front` made room for `n` more elements, and all of
+        // `spare[begin..]`, the `n` slots right before the front, were written.
+        unsafe {
+            self.set_len(self.len() + n);
+        }
+    }
+}
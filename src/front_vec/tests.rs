@@ -61,3 +61,656 @@ fn index_mut() {
     v[1] = 111;
     assert_eq!(v[1], 111);
 }
+
+#[test]
+fn into_vec_keeps_allocation() {
+    let mut v = FrontVec::with_capacity(10);
+    v.push_front(3);
+    v.push_front(2);
+    v.push_front(1);
+
+    let v: Vec<i32> = v.into();
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(v.capacity(), 10);
+}
+
+#[test]
+fn empty_into_vec() {
+    let v: Vec<String> = FrontVec::new().into();
+    assert!(v.is_empty());
+}
+
+#[test]
+fn extend_front_from_slice() {
+    let mut v = FrontVec::from(&[4, 5]);
+    v.extend_front_from_slice(&[1, 2, 3]);
+    assert_eq!(v.as_ref(), &[1, 2, 3, 4, 5]);
+    assert_eq!(v.capacity(), 5);
+    v.extend_front_from_slice(&[]);
+    assert_eq!(v.len(), 5);
+}
+
+#[test]
+fn stack_ops() {
+    let mut v = FrontVec::from(&[1, 2, 3, 4, 5]);
+    assert_eq!(v.peek_front_n(2), Some(&[1, 2][..]));
+    assert_eq!(v.peek_front_n(6), None);
+
+    assert_eq!(v.pop_front_array::<6>(), None);
+    assert_eq!(v.pop_front_array(), Some([1, 2]));
+
+    let popped = v.pop_front_n(2);
+    assert_eq!(&popped[..], &[3, 4]);
+    assert_eq!(&v[..], &[5]);
+
+    v.push_front_array([6, 7]);
+    v.dup_front(2);
+    assert_eq!(&v[..], &[6, 7, 6, 7, 5]);
+
+    v.replace_front(3, 0);
+    assert_eq!(&v[..], &[0, 7, 5]);
+    v.replace_front(0, 9);
+    assert_eq!(&v[..], &[9, 0, 7, 5]);
+}
+
+#[test]
+fn stack_ops_with_drop() {
+    use std::rc::Rc;
+
+    let item = Rc::new(());
+    let mut v = FrontVec::new();
+    for _ in 0..10 {
+        v.push_front_array([item.clone(), item.clone(), item.clone()]);
+    }
+    v.dup_front(5);
+    assert_eq!(Rc::strong_count(&item), 36);
+
+    drop(v.pop_front_n(4));
+    let [_a] = v.pop_front_array().unwrap();
+    v.replace_front(10, item.clone());
+    assert_eq!(v.len(), 21);
+    drop(_a);
+    assert_eq!(Rc::strong_count(&item), 22);
+
+    drop(v);
+    assert_eq!(Rc::strong_count(&item), 1);
+}
+
+#[test]
+#[should_panic]
+fn pop_front_n_past_end() {
+    FrontVec::from(&[1, 2]).pop_front_n(3);
+}
+
+#[test]
+fn prepend_uses_own_headroom() {
+    let mut v = FrontVec::with_capacity(8);
+    v.extend_front_from_slice(&[3, 4]);
+    let mut other = FrontVec::from(&[1, 2]);
+    v.prepend(&mut other);
+    assert_eq!(&v[..], &[1, 2, 3, 4]);
+    assert_eq!(v.capacity(), 8);
+    assert!(other.is_empty());
+}
+
+#[test]
+fn prepend_reuses_other_buffer() {
+    let mut v = FrontVec::from(&[3, 4]);
+    let mut other = FrontVec::with_capacity(8);
+    other.extend_front_from_slice(&[1, 2]);
+    v.prepend(&mut other);
+    assert_eq!(&v[..], &[1, 2, 3, 4]);
+    assert_eq!(v.capacity(), 8);
+    assert!(other.is_empty());
+    assert_eq!(other.capacity(), 2);
+}
+
+#[test]
+fn append_both_ways() {
+    // `other` has headroom for `v`.
+    let mut v = FrontVec::from(&[1, 2]);
+    let mut other = FrontVec::with_capacity(8);
+    other.extend_front_from_slice(&[3, 4]);
+    v.append(&mut other);
+    assert_eq!(&v[..], &[1, 2, 3, 4]);
+    assert_eq!(v.capacity(), 8);
+    assert!(other.is_empty());
+
+    // Only `v` has room, so its elements shift forwards.
+    let mut other = FrontVec::from(&[5]);
+    v.append(&mut other);
+    assert_eq!(&v[..], &[1, 2, 3, 4, 5]);
+    assert_eq!(v.capacity(), 8);
+
+    // Neither has room.
+    let mut other = FrontVec::from(&[6, 7, 8, 9]);
+    v.append(&mut other);
+    assert_eq!(&v[..], &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn split_off() {
+    let mut v = FrontVec::from(&[1, 2, 3, 4, 5]);
+    let back = v.split_off(2);
+    assert_eq!(&v[..], &[1, 2]);
+    assert_eq!(&back[..], &[3, 4, 5]);
+    assert_eq!(v.capacity(), 5);
+
+    let mut v = FrontVec::from(&[1, 2, 3, 4, 5]);
+    let front = v.split_off_front(2);
+    assert_eq!(&front[..], &[1, 2]);
+    assert_eq!(&v[..], &[3, 4, 5]);
+
+    assert!(v.split_off(3).is_empty());
+    assert!(v.split_off_front(0).is_empty());
+    assert_eq!(&v[..], &[3, 4, 5]);
+}
+
+#[test]
+fn append_split_with_drop() {
+    use std::rc::Rc;
+
+    let item = Rc::new(());
+    let mut v = FrontVec::new();
+    v.extend_front(std::iter::repeat_n(item.clone(), 5));
+    let mut other = v.split_off(2);
+    other.prepend(&mut v);
+    v.append(&mut other);
+    v.prepend(&mut FrontVec::from(std::slice::from_ref(&item)));
+    assert_eq!(v.len(), 6);
+    assert_eq!(Rc::strong_count(&item), 7);
+    drop((v, other));
+    assert_eq!(Rc::strong_count(&item), 1);
+}
+
+#[test]
+fn insert_remove() {
+    let mut v = FrontVec::with_capacity(5);
+    v.extend_front_from_slice(&[1, 2, 4]);
+    v.insert(2, 3);
+    v.insert(0, 0);
+    assert_eq!(&v[..], &[0, 1, 2, 3, 4]);
+    assert_eq!(v.capacity(), 5);
+
+    // No headroom left, so this grows.
+    v.insert(5, 5);
+    assert_eq!(&v[..], &[0, 1, 2, 3, 4, 5]);
+    assert_eq!(v.capacity(), 10);
+
+    assert_eq!(v.remove(3), 3);
+    assert_eq!(v.remove(0), 0);
+    assert_eq!(v.remove(3), 5);
+    assert_eq!(&v[..], &[1, 2, 4]);
+
+    let mut v = FrontVec::new();
+    v.insert(0, 'a');
+    assert_eq!(&v[..], &['a']);
+}
+
+#[test]
+#[should_panic]
+fn insert_past_end() {
+    FrontVec::from(&[1]).insert(2, 0);
+}
+
+#[test]
+fn insert_sorted() {
+    let mut v = FrontVec::new();
+    for x in [5, 1, 4, 1, 3, 9, 2, 6] {
+        v.insert_sorted(x);
+    }
+    assert_eq!(&v[..], &[1, 1, 2, 3, 4, 5, 6, 9]);
+
+    assert_eq!(v.insert_sorted_dedup(4), Err(4));
+    assert_eq!(v.insert_sorted_dedup(7), Ok(7));
+    assert_eq!(v.remove_sorted(&1), Some(1));
+    assert_eq!(v.remove_sorted(&8), None);
+    assert_eq!(&v[..], &[1, 2, 3, 4, 5, 6, 7, 9]);
+
+    let mut v = FrontVec::from(&[(1, 'b'), (2, 'a')]);
+    // Equal keys are inserted before the existing ones.
+    assert_eq!(v.insert_sorted_by_key((1, 'c'), |&(k, _)| k), 0);
+    assert_eq!(v.insert_sorted_by((0, 'z'), |a, b| b.1.cmp(&a.1)), 0);
+    assert_eq!(&v[..], &[(0, 'z'), (1, 'c'), (1, 'b'), (2, 'a')]);
+}
+
+#[test]
+fn remove_sorted_borrowed() {
+    let mut v: FrontVec<String> = ["a", "b", "c"].map(String::from).into();
+    assert_eq!(v.remove_sorted("b").as_deref(), Some("b"));
+    assert_eq!(v.len(), 2);
+}
+
+#[test]
+fn cursor_wraps_through_ghost() {
+    let v = FrontVec::from(&[1, 2]);
+    let mut c = v.cursor_front();
+    assert_eq!(c.current(), Some(&1));
+    assert_eq!(c.peek_prev(), None);
+    c.move_next();
+    c.move_next();
+    assert_eq!(c.index(), None);
+    assert_eq!(c.peek_next(), Some(&1));
+    assert_eq!(c.peek_prev(), Some(&2));
+    c.move_next();
+    assert_eq!(c.index(), Some(0));
+    c.move_prev();
+    c.move_prev();
+    assert_eq!(v.cursor_back().current(), c.current());
+
+    let empty = FrontVec::<i32>::new();
+    let mut c = empty.cursor_back();
+    c.move_prev();
+    assert_eq!(c.current(), None);
+}
+
+#[test]
+fn cursor_mut_edits() {
+    let mut v = FrontVec::with_capacity(16);
+    v.extend_front_from_slice(&[1, 3, 5]);
+
+    let mut c = v.cursor_front_mut();
+    c.move_next();
+    c.insert_before(2);
+    c.insert_after(4);
+    assert_eq!(c.current(), Some(&mut 3));
+    assert_eq!(c.index(), Some(2));
+
+    // On the ghost, `insert_before` appends and `insert_after` prepends.
+    c.move_prev();
+    c.move_prev();
+    c.move_prev();
+    assert_eq!(c.index(), None);
+    c.insert_before(6);
+    c.insert_after(0);
+    assert_eq!(c.index(), None);
+    assert_eq!(c.remove_current(), None);
+
+    c.move_next();
+    assert_eq!(c.remove_current(), Some(0));
+    assert_eq!(c.current(), Some(&mut 1));
+    assert_eq!(&v[..], &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(v.capacity(), 16);
+}
+
+#[test]
+fn cursor_splice_before() {
+    // With enough headroom.
+    let mut v = FrontVec::with_capacity(8);
+    v.extend_front_from_slice(&[1, 4]);
+    let mut c = v.cursor_back_mut();
+    c.splice_before(FrontVec::from(&[2, 3]));
+    assert_eq!(c.current(), Some(&mut 4));
+    assert_eq!(&v[..], &[1, 2, 3, 4]);
+    assert_eq!(v.capacity(), 8);
+
+    // Without.
+    let mut v = FrontVec::from(vec![String::from("a"), String::from("d")]);
+    let mut c = v.cursor_back_mut();
+    c.splice_before(FrontVec::from(vec![String::from("b"), String::from("c")]));
+    c.move_next();
+    c.splice_before(FrontVec::from(vec![String::from("e")]));
+    assert_eq!(c.index(), None);
+    assert_eq!(&v[..], &["a", "b", "c", "d", "e"]);
+}
+
+#[test]
+fn grow_realloc_moves_elements_to_back() {
+    let mut v = FrontVec::new();
+    v.extend_front((0..100).map(|i| i.to_string()));
+    v.grow_realloc(250);
+    assert_eq!(v.capacity(), 250);
+    v.grow_realloc(250);
+    assert!(v.iter().map(|s| s.parse::<i32>().unwrap()).eq(0..100));
+
+    // Big enough that `push_front` grows with `realloc` too.
+    let mut v = FrontVec::new();
+    for i in (0..100_000u32).rev() {
+        v.push_front(i);
+    }
+    assert!(v.iter().copied().eq(0..100_000));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn read_consumes_from_front() {
+    use std::io::{BufRead, Read};
+
+    let mut v = FrontVec::from(&b"hello\nworld"[..]);
+    let mut buf = [0; 3];
+    assert_eq!(v.read(&mut buf).unwrap(), 3);
+    assert_eq!(&buf, b"hel");
+
+    let mut line = String::new();
+    v.read_line(&mut line).unwrap();
+    assert_eq!(line, "lo\n");
+
+    // The consumed space can be prepended into again without reallocating.
+    let cap = v.capacity();
+    v.push_front(b' ');
+    assert_eq!(v.capacity(), cap);
+
+    let mut rest = Vec::new();
+    v.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, b" world");
+    assert!(v.is_empty());
+    assert_eq!(v.read(&mut buf).unwrap(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn read_exact_past_end() {
+    use std::io::{ErrorKind, Read};
+
+    let mut v = FrontVec::from(&[1, 2, 3]);
+    let mut buf = [0; 2];
+    v.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [1, 2]);
+    let err = v.read_exact(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn buf_reads_from_front() {
+    use bytes::Buf;
+
+    let mut v = FrontVec::from(&[0x12, 0x34, 0x56, 0x78, 0x9A][..]);
+    assert_eq!(v.remaining(), 5);
+    assert_eq!(v.get_u16(), 0x1234);
+    assert_eq!(v.get_u16_le(), 0x7856);
+    assert_eq!(v.chunk(), &[0x9A]);
+    v.advance(1);
+    assert!(!v.has_remaining());
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn into_bytes_is_zero_copy() {
+    let v = FrontVec::from(&b"abc"[..]);
+    let ptr = v.as_ptr();
+    let b = bytes::Bytes::from(v);
+    assert_eq!(b.as_ptr(), ptr);
+    assert_eq!(&b[..], b"abc");
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn prepend_buf_mut_keeps_write_order() {
+    use bytes::BufMut;
+
+    let mut v = FrontVec::from(&b"payload"[..]);
+    {
+        let mut buf = v.prepend_buf_mut();
+        buf.put_u8(1);
+        buf.put_u32(0x0203_0405);
+        buf.put_slice(b"hdr");
+        assert_eq!(buf.written(), 8);
+    }
+    assert_eq!(&v[..], b"\x01\x02\x03\x04\x05hdrpayload");
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn prepend_buf_mut_grows() {
+    use bytes::BufMut;
+
+    let mut v = FrontVec::<u8>::new();
+    let data: Vec<u8> = (0..=255).cycle().take(1000).collect();
+    {
+        let mut buf = v.prepend_buf_mut();
+        for chunk in data.chunks(7) {
+            buf.put_slice(chunk);
+        }
+    }
+    assert_eq!(&v[..], &data[..]);
+
+    {
+        let mut buf = v.prepend_buf_mut();
+        buf.put_bytes(b'x', 100);
+    }
+    assert_eq!(&v[..100], &[b'x'; 100][..]);
+    assert_eq!(&v[100..], &data[..]);
+}
+
+#[test]
+fn front_vec_macro() {
+    use std::rc::Rc;
+
+    let v: FrontVec<i32> = crate::front_vec![];
+    assert_eq!(v.capacity(), 0);
+
+    let v = crate::front_vec![1, 2, 3,];
+    assert_eq!(&v[..], &[1, 2, 3]);
+    assert_eq!(v.capacity(), 3);
+
+    let item = Rc::new(());
+    let v = crate::front_vec![item.clone(); 3];
+    assert_eq!(v.capacity(), 3);
+    assert_eq!(Rc::strong_count(&item), 4);
+    drop(v);
+
+    let v = crate::front_vec![cap = 8; item.clone(), item.clone()];
+    assert_eq!(v.len(), 2);
+    assert_eq!(v.capacity(), 8);
+    assert_eq!(Rc::strong_count(&item), 3);
+
+    let v = crate::front_vec![cap = 1; 1, 2];
+    assert_eq!(v.capacity(), 2);
+}
+
+#[cfg(feature = "stats")]
+#[test]
+fn stats_count_growth() {
+    use crate::stats::{self, GrowthEvent};
+    use std::cell::RefCell;
+
+    thread_local! {
+        static EVENTS: RefCell<Vec<GrowthEvent>> = const { RefCell::new(Vec::new()) };
+    }
+    fn hook(event: &GrowthEvent) {
+        EVENTS.with(|events| events.borrow_mut().push(*event));
+    }
+
+    // Other tests may run concurrently, so only lower bounds can be checked
+    // against the global counters. The hook's events are per thread though.
+    stats::set_growth_hook(Some(hook));
+    let before = stats::snapshot();
+
+    let mut v = FrontVec::<u32>::new();
+    for i in 0..16 {
+        v.push_front(i);
+    }
+    // Full, so this grows too.
+    v.insert(3, 100);
+
+    let after = stats::snapshot();
+    assert!(after.allocations - before.allocations >= 4);
+    assert!(after.growths - before.growths >= 3);
+    assert!(after.bytes_copied - before.bytes_copied >= 4 * (4 + 8 + 16));
+    assert!(after.peak_capacity_bytes >= 4 * 32);
+
+    let events = EVENTS.with(|events| events.take());
+    let caps: Vec<_> = events.iter().map(|e| e.new_capacity_bytes / 4).collect();
+    assert_eq!(caps, [8, 16, 32]);
+    assert_eq!(events[2].bytes_copied, 4 * 16);
+}
+
+#[cfg(all(feature = "mmap", target_os = "linux"))]
+mod mmap {
+    use crate::{FrontVec, SharedFrontVec};
+
+    const MIB: usize = 1024 * 1024;
+
+    #[test]
+    fn many_growth_cycles() {
+        let mut v = FrontVec::<u32>::new();
+        let mut caps = Vec::new();
+        for i in (0..(4 * MIB) as u32).rev() {
+            v.push_front(i);
+            if caps.last() != Some(&v.capacity()) {
+                caps.push(v.capacity());
+            }
+        }
+        // Several of those growths were remaps.
+        assert!(caps.iter().filter(|&&cap| cap * 4 >= MIB).count() >= 3);
+        assert!(v.iter().copied().eq(0..(4 * MIB) as u32));
+
+        while v.len() > 10 {
+            v.pop_front_n(v.len() / 2);
+        }
+        let len = v.len();
+        assert!(v
+            .iter()
+            .copied()
+            .eq((4 * MIB - len) as u32..(4 * MIB) as u32));
+    }
+
+    #[test]
+    fn odd_sized_elements() {
+        // 3-byte elements never fill a mapping exactly, so each buffer starts
+        // at a different offset into its first page.
+        let item = |i: usize| [i as u8, (i >> 8) as u8, (i >> 16) as u8];
+
+        let mut v = FrontVec::<[u8; 3]>::new();
+        let n = 3 * MIB;
+        for i in (0..n).rev() {
+            v.push_front(item(i));
+        }
+        assert!(v.iter().copied().eq((0..n).map(item)));
+
+        // Grow by odd amounts too, within and across mappings.
+        for extra in [1, 4095, 4096, 4097, MIB + 1] {
+            v.reserve_front(v.capacity() - v.len() + extra);
+            v.push_front(item(0));
+            assert_eq!(v[1], item(0));
+            assert_eq!(v[v.len() - 1], item(n - 1));
+        }
+    }
+
+    #[test]
+    fn drops_and_conversions() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut v = FrontVec::with_capacity(MIB);
+        v.extend_front(std::iter::repeat_n(rc.clone(), MIB));
+        v.push_front(rc.clone());
+        assert_eq!(Rc::strong_count(&rc), MIB + 2);
+        drop(v);
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let big: Vec<u64> = (0..MIB as u64).collect();
+        let v = FrontVec::from(big.clone());
+        let back: Vec<u64> = v.into();
+        assert_eq!(back, big);
+
+        let mut v = FrontVec::from(big.clone());
+        v.grow_realloc(2 * MIB);
+        assert!(v.iter().eq(big.iter()));
+
+        let shared = SharedFrontVec::from(v);
+        let tail = shared.tail().unwrap();
+        assert_eq!(tail[0], 1);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_varies_slack() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let mut seen = [false; 3];
+    for seed in 0..=255u8 {
+        let data: Vec<u8> = (0..64)
+            .map(|i| seed.wrapping_mul(31).wrapping_add(i))
+            .collect();
+        let mut u = Unstructured::new(&data);
+        let mut v = FrontVec::<u16>::arbitrary(&mut u).unwrap();
+        let slack = v.capacity() - v.len();
+        seen[match slack {
+            0 => 0,
+            1..=3 => 1,
+            _ => 2,
+        }] = true;
+
+        let before: Vec<u16> = v.iter().copied().collect();
+        v.push_front(7);
+        assert_eq!(v[0], 7);
+        assert_eq!(&v[1..], &before[..]);
+    }
+    assert_eq!(seen, [true; 3]);
+}
+
+#[cfg(feature = "proptest")]
+mod prop {
+    use crate::FrontVec;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn arbitrary_front_vecs_are_usable(mut v in any::<FrontVec<String>>(), s: String) {
+            prop_assert!(v.capacity() >= v.len());
+            let before: Vec<String> = v.iter().cloned().collect();
+            v.push_front(s.clone());
+            prop_assert_eq!(&v[0], &s);
+            prop_assert_eq!(&v[1..], &before[..]);
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    use crate::FrontVec;
+    use rayon::prelude::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn par_iter() {
+        let mut v: FrontVec<u64> = (0..10_000u64).into_par_iter().collect();
+        assert!(v.iter().copied().eq(0..10_000));
+
+        (&mut v).into_par_iter().for_each(|x| *x *= 2);
+        assert_eq!((&v).into_par_iter().sum::<u64>(), 9_999 * 10_000);
+
+        v.pop_front_n(5_000);
+        let owned: Vec<u64> = v.into_par_iter().map(|x| x / 2).collect();
+        assert!(owned.into_iter().eq(5_000..10_000));
+    }
+
+    #[test]
+    fn par_extend_front_keeps_order() {
+        let mut v = FrontVec::from([String::from("end")]);
+        v.par_extend_front((0..1000).into_par_iter().map(|i| i.to_string()));
+        v.par_extend_front(Vec::<String>::new());
+        v.par_extend_front(vec![String::from("start")]);
+
+        assert_eq!(v.len(), 1002);
+        assert_eq!(v[0], "start");
+        assert!(v[1..1001]
+            .iter()
+            .eq((0..1000).map(|i| i.to_string()).collect::<Vec<_>>().iter()));
+        assert_eq!(v[1001], "end");
+    }
+
+    #[test]
+    fn par_extend_front_uses_headroom() {
+        let mut v = FrontVec::<u32>::with_capacity(64);
+        v.push_front(1);
+        let buf = v.as_ptr();
+        v.par_extend_front(vec![0; 63]);
+        // Filled in place.
+        assert_eq!(v.capacity(), 64);
+        assert_eq!(v.as_ptr(), buf.wrapping_sub(63));
+    }
+
+    #[test]
+    fn par_extend_front_drops_items() {
+        let item = Arc::new(());
+        let mut v = FrontVec::new();
+        v.par_extend_front((0..100).into_par_iter().map(|_| Arc::clone(&item)));
+        assert_eq!(Arc::strong_count(&item), 101);
+        drop(v);
+        assert_eq!(Arc::strong_count(&item), 1);
+    }
+}
@@ -1,10 +1,45 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![feature(ptr_internals, maybe_uninit_slice)]
 #![deny(unsafe_op_in_unsafe_fn)]
 #![allow(clippy::module_inception)]
 
 extern crate alloc;
 
+mod macros;
+
+#[cfg(target_has_atomic = "ptr")]
+mod arc_front_vec;
+pub mod der;
+pub mod flatbuffers;
+mod front_array_string;
+mod front_array_vec;
+mod front_buf;
+#[cfg(all(feature = "std", unix))]
+mod front_path;
+mod front_small_string;
+mod front_small_vec;
 mod front_string;
 mod front_vec;
+pub mod proto;
+mod shared_front_vec;
+#[cfg(feature = "stats")]
+pub mod stats;
 
-pub use crate::{front_string::FrontString, front_vec::FrontVec};
+#[cfg(target_has_atomic = "ptr")]
+pub use crate::arc_front_vec::ArcFrontVec;
+#[cfg(all(feature = "std", unix))]
+pub use crate::front_path::{FrontOsString, FrontPathBuf};
+#[cfg(feature = "std")]
+pub use crate::front_string::FrontStringReader;
+#[cfg(feature = "bytes")]
+pub use crate::front_vec::PrependBufMut;
+pub use crate::{
+    front_array_string::FrontArrayString,
+    front_array_vec::FrontArrayVec,
+    front_buf::{FrontBuf, LenPrefix},
+    front_small_string::FrontSmallString,
+    front_small_vec::FrontSmallVec,
+    front_string::{Float, FrontString, FrontStringBuilder, Integer},
+    front_vec::{Cursor, CursorMut, FrontVec},
+    shared_front_vec::SharedFrontVec,
+};
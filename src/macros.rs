@@ -0,0 +1,64 @@
+/// Creates a [`FrontVec`](crate::FrontVec) containing the arguments, like
+/// `vec!`.
+///
+/// The elements are moved in without cloning, and exactly one allocation is
+/// made.
+///
+/// - `front_vec![a, b, c]` creates a `FrontVec` with exactly those elements.
+/// - `front_vec![x; n]` creates a `FrontVec` with `n` clones of `x`.
+/// - `front_vec![cap = 64; a, b]` creates a `FrontVec` with those elements and
+///   a capacity of at least 64, leaving headroom for prepending.
+///
+/// # Example
+///
+/// ```
+/// # use front_vec::front_vec;
+/// let v = front_vec![1, 2, 3];
+/// assert_eq!(&v[..], &[1, 2, 3]);
+///
+/// let v = front_vec![0u8; 4];
+/// assert_eq!(&v[..], &[0, 0, 0, 0]);
+///
+/// let mut v = front_vec![cap = 8; String::from("b")];
+/// v.push_front(String::from("a"));
+/// assert_eq!(v.capacity(), 8);
+/// ```
+#[macro_export]
+macro_rules! front_vec {
+    (cap = $cap:expr; $($x:expr),* $(,)?) => {{
+        let array = [$($x),*];
+        let mut v = $crate::FrontVec::with_capacity(::core::cmp::max($cap, array.len()));
+        v.push_front_array(array);
+        v
+    }};
+    ($elem:expr; $n:expr) => {{
+        let n = $n;
+        let mut v = $crate::FrontVec::with_capacity(n);
+        v.extend_front(::core::iter::repeat_n($elem, n));
+        v
+    }};
+    ($($x:expr),* $(,)?) => {
+        $crate::FrontVec::from([$($x),*])
+    };
+}
+
+/// Creates a [`FrontString`](crate::FrontString) from format arguments, like
+/// `format!`, with a single allocation of exactly the right size. See
+/// [`FrontString::from_fmt`](crate::FrontString::from_fmt).
+///
+/// # Example
+///
+/// ```
+/// # use front_vec::front_string;
+/// # use assert2::assert;
+/// let name = "world";
+/// let s = front_string!("Hello, {name}!");
+/// assert!(s == "Hello, world!");
+/// assert!(s.capacity() == s.len());
+/// ```
+#[macro_export]
+macro_rules! front_string {
+    ($($arg:tt)*) => {
+        $crate::FrontString::from_fmt(::core::format_args!($($arg)*))
+    };
+}
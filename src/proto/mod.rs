@@ -0,0 +1,6 @@
+mod proto;
+
+#[cfg(test)]
+mod tests;
+
+pub use proto::*;
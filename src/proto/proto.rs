@@ -0,0 +1,140 @@
+//! A protobuf wire-format encoder which writes messages back to front.
+//!
+//! Encoding back to front (as upb does) means a nested message's body is
+//! written before its length is prepended, so lengths never need to be
+//! computed in a separate pass. The catch is that fields must be prepended in
+//! *reverse* order: to produce the same bytes as a standard encoder writing
+//! fields 1, 2 and 3, prepend field 3 first and field 1 last.
+//!
+//! # Example
+//!
+//! ```
+//! # use front_vec::proto::{Encoder, WireType};
+//! // message Test { int32 a = 1; string b = 2; }
+//! let mut enc = Encoder::new();
+//! enc.prepend_bytes_field(2, b"hi");
+//! enc.prepend_varint(150);
+//! enc.prepend_tag(1, WireType::Varint);
+//! assert_eq!(enc.as_bytes(), b"\x08\x96\x01\x12\x02hi");
+//! ```
+
+use crate::{front_buf::impl_front_buf_wrapper, FrontBuf, FrontVec};
+
+/// The largest field number allowed by protobuf.
+pub const MAX_FIELD_NUMBER: u32 = (1 << 29) - 1;
+
+/// How a field's value is encoded, stored in the low 3 bits of its tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum WireType {
+    /// `int32`, `int64`, `uint32`, `uint64`, `sint32`, `sint64`, `bool` and
+    /// `enum`.
+    Varint = 0,
+    /// `fixed64`, `sfixed64` and `double`.
+    I64 = 1,
+    /// Length-delimited: `string`, `bytes`, embedded messages and packed
+    /// repeated fields.
+    Len = 2,
+    /// `fixed32`, `sfixed32` and `float`.
+    I32 = 5,
+}
+
+/// Builds a protobuf message from back to front. See the [module
+/// docs](crate::proto).
+#[derive(Debug, Clone, Default)]
+pub struct Encoder {
+    buf: FrontVec<u8>,
+}
+
+impl_front_buf_wrapper!(Encoder, encoder);
+
+impl Encoder {
+    /// Prepends `n` as a base 128 varint, taking 1 to 10 bytes.
+    ///
+    /// Negative `int32` and `int64` values are sign-extended to 64 bits, so
+    /// encode them with `prepend_varint(n as i64 as u64)`, which always takes
+    /// 10 bytes. `sint32`/`sint64` values should use
+    /// [`prepend_zigzag`](Self::prepend_zigzag) instead.
+    pub fn prepend_varint(&mut self, mut n: u64) {
+        let mut bytes = [0; 10];
+        let mut len = 0;
+        while n >= 0x80 {
+            bytes[len] = n as u8 | 0x80;
+            n >>= 7;
+            len += 1;
+        }
+        bytes[len] = n as u8;
+        self.buf.prepend_slice(&bytes[..=len]);
+    }
+
+    /// Prepends `n` as a zigzag-encoded varint, as used by `sint32` and
+    /// `sint64`, so that small negative numbers stay short.
+    pub fn prepend_zigzag(&mut self, n: i64) {
+        self.prepend_varint(((n << 1) ^ (n >> 63)) as u64);
+    }
+
+    /// Prepends the tag starting a field. Since the tag precedes the value in
+    /// the output, prepend it *after* the value.
+    ///
+    /// # Panics
+    /// Panics if `field` isn't in `1..=MAX_FIELD_NUMBER`.
+    pub fn prepend_tag(&mut self, field: u32, wire_type: WireType) {
+        assert!(
+            (1..=MAX_FIELD_NUMBER).contains(&field),
+            "invalid field number {field}"
+        );
+        self.prepend_varint(u64::from(field) << 3 | wire_type as u64);
+    }
+
+    /// Prepends a little-endian `fixed32`, `sfixed32` (cast to `u32`) or
+    /// `float` (with `f32::to_bits`).
+    pub fn prepend_fixed32(&mut self, n: u32) {
+        self.buf.prepend_u32_le(n);
+    }
+
+    /// Prepends a little-endian `fixed64`, `sfixed64` (cast to `u64`) or
+    /// `double` (with `f64::to_bits`).
+    pub fn prepend_fixed64(&mut self, n: u64) {
+        self.buf.prepend_u64_le(n);
+    }
+
+    /// Prepends a whole length-delimited field holding `bytes`, such as a
+    /// `string` or `bytes` field.
+    pub fn prepend_bytes_field(&mut self, field: u32, bytes: &[u8]) {
+        self.prepend_raw(bytes);
+        self.prepend_varint(bytes.len() as u64);
+        self.prepend_tag(field, WireType::Len);
+    }
+
+    /// Lets `write_body` prepend an embedded message (or a packed repeated
+    /// field), then prepends its length and the field's tag. Returns whatever
+    /// `write_body` returns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use front_vec::proto::{Encoder, WireType};
+    /// // message Outer { Inner c = 3; }  message Inner { int32 a = 1; }
+    /// let mut enc = Encoder::new();
+    /// enc.prepend_nested(3, |inner| {
+    ///     inner.prepend_varint(150);
+    ///     inner.prepend_tag(1, WireType::Varint);
+    /// });
+    /// assert_eq!(enc.as_bytes(), b"\x1a\x03\x08\x96\x01");
+    /// ```
+    pub fn prepend_nested<R>(&mut self, field: u32, write_body: impl FnOnce(&mut Self) -> R) -> R {
+        let len_before = self.len();
+        let ret = write_body(self);
+        let body_len = self.len() - len_before;
+
+        self.prepend_varint(body_len as u64);
+        self.prepend_tag(field, WireType::Len);
+        ret
+    }
+}
+
+impl From<Encoder> for FrontVec<u8> {
+    fn from(enc: Encoder) -> Self {
+        enc.into_inner()
+    }
+}
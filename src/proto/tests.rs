@@ -0,0 +1,183 @@
+use super::{Encoder, WireType, MAX_FIELD_NUMBER};
+
+#[test]
+fn varints() {
+    let cases: [(u64, &[u8]); 7] = [
+        (0, &[0x00]),
+        (1, &[0x01]),
+        (127, &[0x7f]),
+        (128, &[0x80, 0x01]),
+        (150, &[0x96, 0x01]),
+        (300, &[0xac, 0x02]),
+        (
+            u64::MAX,
+            &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01],
+        ),
+    ];
+    for (n, expected) in cases {
+        let mut enc = Encoder::new();
+        enc.prepend_varint(n);
+        assert_eq!(enc.as_bytes(), expected, "{n}");
+    }
+
+    // An `int32` of -1 is sign-extended.
+    let mut enc = Encoder::new();
+    enc.prepend_varint(-1i32 as i64 as u64);
+    assert_eq!(enc.as_bytes(), cases[6].1);
+}
+
+#[test]
+fn zigzag() {
+    let cases: [(i64, &[u8]); 7] = [
+        (0, &[0x00]),
+        (-1, &[0x01]),
+        (1, &[0x02]),
+        (-2, &[0x03]),
+        (2147483647, &[0xfe, 0xff, 0xff, 0xff, 0x0f]),
+        (-2147483648, &[0xff, 0xff, 0xff, 0xff, 0x0f]),
+        (
+            i64::MIN,
+            &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01],
+        ),
+    ];
+    for (n, expected) in cases {
+        let mut enc = Encoder::new();
+        enc.prepend_zigzag(n);
+        assert_eq!(enc.as_bytes(), expected, "{n}");
+    }
+}
+
+#[test]
+fn tags() {
+    let cases: [(u32, WireType, &[u8]); 5] = [
+        (1, WireType::Varint, &[0x08]),
+        (2, WireType::Len, &[0x12]),
+        (5, WireType::I32, &[0x2d]),
+        (16, WireType::I64, &[0x81, 0x01]),
+        (
+            MAX_FIELD_NUMBER,
+            WireType::Varint,
+            &[0xf8, 0xff, 0xff, 0xff, 0x0f],
+        ),
+    ];
+    for (field, wire_type, expected) in cases {
+        let mut enc = Encoder::new();
+        enc.prepend_tag(field, wire_type);
+        assert_eq!(enc.as_bytes(), expected, "{field}");
+    }
+}
+
+#[test]
+#[should_panic = "invalid field number 0"]
+fn field_zero() {
+    Encoder::new().prepend_tag(0, WireType::Varint);
+}
+
+#[test]
+fn fixed() {
+    let mut enc = Encoder::new();
+    enc.prepend_fixed32(1.0f32.to_bits());
+    enc.prepend_tag(1, WireType::I32);
+    assert_eq!(enc.as_bytes(), [0x0d, 0x00, 0x00, 0x80, 0x3f]);
+
+    let mut enc = Encoder::new();
+    enc.prepend_fixed64(-2i64 as u64);
+    enc.prepend_tag(2, WireType::I64);
+    assert_eq!(
+        enc.as_bytes(),
+        [0x11, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+}
+
+// The examples from https://protobuf.dev/programming-guides/encoding/.
+
+#[test]
+fn int_field() {
+    // message Test1 { optional int32 a = 1; }, a = 150
+    let mut enc = Encoder::new();
+    enc.prepend_varint(150);
+    enc.prepend_tag(1, WireType::Varint);
+    assert_eq!(enc.as_bytes(), [0x08, 0x96, 0x01]);
+}
+
+#[test]
+fn string_field() {
+    // message Test2 { optional string b = 2; }, b = "testing"
+    let mut enc = Encoder::new();
+    enc.prepend_bytes_field(2, b"testing");
+    assert_eq!(enc.as_bytes(), b"\x12\x07testing");
+}
+
+#[test]
+fn nested_message() {
+    // message Test3 { optional Test1 c = 3; }, c.a = 150
+    let mut enc = Encoder::new();
+    enc.prepend_nested(3, |e| {
+        e.prepend_varint(150);
+        e.prepend_tag(1, WireType::Varint);
+    });
+    assert_eq!(enc.as_bytes(), [0x1a, 0x03, 0x08, 0x96, 0x01]);
+}
+
+#[test]
+fn packed_repeated() {
+    // message Test5 { repeated int32 f = 6 [packed = true]; }, f = [3, 270, 86942]
+    let mut enc = Encoder::new();
+    enc.prepend_nested(6, |e| {
+        for n in [3, 270, 86942].into_iter().rev() {
+            e.prepend_varint(n);
+        }
+    });
+    assert_eq!(
+        enc.as_bytes(),
+        [0x32, 0x06, 0x03, 0x8e, 0x02, 0x9e, 0xa7, 0x05]
+    );
+}
+
+#[test]
+fn fields_in_order() {
+    // Test1 { a: 150 }, then Test2 { b: "testing" } as fields 1 and 2 of one
+    // message: the last field is prepended first.
+    let mut enc = Encoder::new();
+    enc.prepend_bytes_field(2, b"testing");
+    enc.prepend_varint(150);
+    enc.prepend_tag(1, WireType::Varint);
+    assert_eq!(enc.as_bytes(), b"\x08\x96\x01\x12\x07testing");
+}
+
+#[test]
+fn long_nested_body() {
+    let body = [0xaa; 200];
+    let (bytes, ret) = {
+        let mut e = Encoder::with_headroom(8);
+        let ret = e.prepend_nested(1, |e| {
+            e.prepend_nested(2, |e| e.prepend_raw(&body));
+            "done"
+        });
+        (e.into_inner(), ret)
+    };
+    assert_eq!(ret, "done");
+    // Outer: tag, 2-byte length 203. Inner: tag, 2-byte length 200.
+    assert_eq!(&bytes[..6], [0x0a, 0xcb, 0x01, 0x12, 0xc8, 0x01]);
+    assert_eq!(&bytes[6..], body);
+}
+
+#[test]
+fn many_fields_grow_geometrically() {
+    let mut enc = Encoder::new();
+    let mut cap = enc.capacity();
+    let mut growths = 0;
+    for i in 0..10_000u64 {
+        enc.prepend_bytes_field(2, b"ab");
+        enc.prepend_varint(i);
+        enc.prepend_tag(1, WireType::Varint);
+        if enc.capacity() != cap {
+            cap = enc.capacity();
+            growths += 1;
+        }
+    }
+    assert!(enc.as_bytes().starts_with(b"\x08\x8f\x4e\x12\x02ab"));
+    // Doubling from 4 bytes reaches the ~70 000 bytes in 15 growths, where
+    // growing by exactly what's prepended would take 40 000.
+    assert!(growths <= 16, "{growths} growths");
+}
@@ -0,0 +1,10 @@
+mod shared;
+mod shared_front_vec;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use self::shared::{grown_cap, Shared};
+#[cfg(target_has_atomic = "ptr")]
+pub(crate) use self::shared::{impl_shared_front_vec, Claims};
+pub use self::shared_front_vec::*;
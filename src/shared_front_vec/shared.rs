@@ -0,0 +1,323 @@
+//! The buffer behind [`SharedFrontVec`](crate::SharedFrontVec) and
+//! [`ArcFrontVec`](crate::ArcFrontVec), generic over how its claimed slots are
+//! counted.
+
+use core::{
+    mem::{self, MaybeUninit},
+    ptr::{NonNull, Unique},
+};
+
+use crate::{
+    front_vec::{alloc_buf, dealloc_buf, record_growth},
+    FrontVec,
+};
+
+/// The number of claimed slots of a [`Shared`] buffer.
+pub(crate) trait Claims {
+    fn new(claimed: usize) -> Self;
+
+    /// The number of claimed slots, all of which are initialized.
+    fn current(&self) -> usize;
+
+    fn current_mut(&mut self) -> &mut usize;
+
+    /// Claims the next free slot if exactly `len` slots are claimed. The caller
+    /// then has the only right to write that slot.
+    fn try_advance(&self, len: usize) -> bool;
+}
+
+pub(crate) struct Shared<T, C: Claims> {
+    buf: NonNull<MaybeUninit<T>>,
+    pub(crate) cap: usize,
+    /// The number of initialized elements at the back of `buf`. Slots are
+    /// claimed one at a time from back to front and, while the buffer is
+    /// shared, are never released or written again.
+    claimed: C,
+}
+
+impl<T, C: Claims> Shared<T, C> {
+    pub(crate) fn with_capacity(cap: usize) -> Self {
+        Self {
+            buf: alloc_buf(cap).into(),
+            cap,
+            claimed: C::new(0),
+        }
+    }
+
+    /// Takes over the `FrontVec`'s buffer, with its elements claimed.
+    pub(crate) fn from_front_vec(v: FrontVec<T>) -> Self {
+        let (buf, cap, len) = v.into_raw_parts();
+        Self {
+            buf: buf.into(),
+            cap,
+            claimed: C::new(len),
+        }
+    }
+
+    /// The last `len` elements of the buffer.
+    pub(crate) fn back(&self, len: usize) -> &[T] {
+        assert!(len <= self.claimed.current());
+        // SAFETY: The last `claimed` slots are initialized, and they're never
+        // mutated while the buffer is shared.
+        unsafe {
+            core::slice::from_raw_parts(self.buf.as_ptr().add(self.cap - len) as *const T, len)
+        }
+    }
+
+    /// Writes `val` into the next free slot, provided that a list of length
+    /// `len` owns it. Otherwise hands `val` back.
+    pub(crate) fn try_claim(&self, len: usize, val: T) -> Result<(), T> {
+        if len == self.cap || !self.claimed.try_advance(len) {
+            return Err(val);
+        }
+
+        // SAFETY: The slot right in front of the previously claimed region is
+        // in bounds, and claiming it gave us the only right to write it. No
+        // list can view it until the caller extends its own length, after the
+        // write.
+        unsafe {
+            self.buf
+                .as_ptr()
+                .add(self.cap - len - 1)
+                .write(MaybeUninit::new(val));
+        }
+        Ok(())
+    }
+
+    /// Drops the claimed elements in front of the last `len`. Since this takes
+    /// `&mut self`, no list can be viewing them.
+    pub(crate) fn truncate_claimed(&mut self, len: usize) {
+        let claimed = *self.claimed.current_mut();
+        if claimed <= len {
+            return;
+        }
+
+        *self.claimed.current_mut() = len;
+        // SAFETY: `cap - claimed..cap - len` was initialized, and is no longer
+        // reachable now that `claimed` has been updated.
+        unsafe {
+            let front = self.buf.as_ptr().add(self.cap - claimed) as *mut T;
+            core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(front, claimed - len));
+        }
+    }
+
+    /// Moves the claimed elements into a new buffer of capacity `new_cap`.
+    pub(crate) fn grow(&mut self, new_cap: usize) {
+        let claimed = *self.claimed.current_mut();
+        let mut new = Self::with_capacity(new_cap);
+
+        // SAFETY: The new buffer has room for at least `claimed` elements, and
+        // the old buffer forgets about them right after.
+        unsafe {
+            new.buf
+                .as_ptr()
+                .add(new_cap - claimed)
+                .copy_from_nonoverlapping(self.buf.as_ptr().add(self.cap - claimed), claimed);
+        }
+        *new.claimed.current_mut() = claimed;
+        *self.claimed.current_mut() = 0;
+        record_growth::<T>(self.cap, new_cap, claimed);
+
+        drop(mem::replace(self, new));
+    }
+}
+
+impl<T, C: Claims> Drop for Shared<T, C> {
+    fn drop(&mut self) {
+        self.truncate_claimed(0);
+        // SAFETY: The elements were just dropped, and `self` is never used
+        // again.
+        unsafe {
+            dealloc_buf(Unique::from(self.buf), self.cap);
+        }
+    }
+}
+
+/// The capacity of a buffer that a full list of length `len` grows into.
+pub(crate) fn grown_cap(len: usize) -> usize {
+    usize::max(4, 2 * len)
+}
+
+/// Implements the list API of a type `$name<T>` with the fields
+/// `shared: $rc<Shared<T, _>>` and `len: usize`, where `$rc` is `Rc` or `Arc`.
+macro_rules! impl_shared_front_vec {
+    ($name:ident, $rc:ident) => {
+        impl<T> $name<T> {
+            pub fn new() -> Self {
+                Self::with_capacity(0)
+            }
+
+            pub fn with_capacity(cap: usize) -> Self {
+                Self {
+                    shared: $rc::new($crate::shared_front_vec::Shared::with_capacity(cap)),
+                    len: 0,
+                }
+            }
+
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// The capacity of the (possibly shared) underlying buffer.
+            pub fn capacity(&self) -> usize {
+                self.shared.cap
+            }
+
+            /// Returns `true` if both lists are views into the same buffer.
+            pub fn shares_buffer(&self, other: &Self) -> bool {
+                $rc::ptr_eq(&self.shared, &other.shared)
+            }
+
+            /// Returns the first element in O(1).
+            pub fn head(&self) -> Option<&T> {
+                self.first()
+            }
+
+            /// Returns the list without its first element in O(1), sharing the
+            /// buffer.
+            pub fn tail(&self) -> Option<Self> {
+                self.split_first().map(|(_, tail)| tail)
+            }
+
+            pub fn split_first(&self) -> Option<(&T, Self)> {
+                let head = self.first()?;
+                let tail = Self {
+                    shared: self.shared.clone(),
+                    len: self.len - 1,
+                };
+                Some((head, tail))
+            }
+
+            /// Shortens the list, keeping the **last** `len` elements, in O(1).
+            /// The dropped elements stay alive for as long as another list
+            /// views them.
+            pub fn truncate(&mut self, len: usize) {
+                self.len = usize::min(len, self.len);
+            }
+        }
+
+        impl<T: Clone> $name<T> {
+            /// Builds the list `head : tail`, sharing `tail`'s buffer if
+            /// possible.
+            pub fn cons(head: T, tail: &Self) -> Self {
+                let mut list = tail.clone();
+                list.push_front(head);
+                list
+            }
+
+            /// Prepends `val`. This is done in place if this list owns the next
+            /// free slot of the buffer, otherwise the elements are first copied
+            /// into a new buffer which this list owns.
+            ///
+            /// Elements are only cloned if the buffer is shared with another
+            /// list.
+            pub fn push_front(&mut self, val: T) {
+                use $crate::shared_front_vec::{grown_cap, Shared};
+
+                if let Some(shared) = $rc::get_mut(&mut self.shared) {
+                    // Nobody else can see the buffer, so any slots in front of
+                    // this list can be reused, and growing can move instead of
+                    // clone.
+                    shared.truncate_claimed(self.len);
+                    if self.len == shared.cap {
+                        shared.grow(grown_cap(self.len));
+                    }
+                }
+
+                let val = match self.shared.try_claim(self.len, val) {
+                    Ok(()) => {
+                        self.len += 1;
+                        return;
+                    }
+                    Err(val) => val,
+                };
+
+                // Copy on write.
+                let new = Shared::with_capacity(grown_cap(self.len));
+                for (claimed, item) in self.iter().rev().enumerate() {
+                    // Can't fail, `new` is exclusively ours and has room for
+                    // `len + 1`.
+                    let _ = new.try_claim(claimed, item.clone());
+                }
+                let _ = new.try_claim(self.len, val);
+
+                self.shared = $rc::new(new);
+                self.len += 1;
+            }
+        }
+
+        impl<T> AsRef<[T]> for $name<T> {
+            fn as_ref(&self) -> &[T] {
+                self.shared.back(self.len)
+            }
+        }
+
+        impl<T> ::core::ops::Deref for $name<T> {
+            type Target = [T];
+
+            fn deref(&self) -> &Self::Target {
+                self.as_ref()
+            }
+        }
+
+        impl<T> Clone for $name<T> {
+            /// O(1): the clone shares this list's buffer.
+            fn clone(&self) -> Self {
+                Self {
+                    shared: self.shared.clone(),
+                    len: self.len,
+                }
+            }
+        }
+
+        impl<T: ::core::fmt::Debug> ::core::fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let slice: &[T] = self.as_ref();
+                slice.fmt(f)
+            }
+        }
+
+        impl<T> From<$crate::FrontVec<T>> for $name<T> {
+            /// Takes over the `FrontVec`'s buffer without copying.
+            fn from(v: $crate::FrontVec<T>) -> Self {
+                let len = v.len();
+                Self {
+                    shared: $rc::new($crate::shared_front_vec::Shared::from_front_vec(v)),
+                    len,
+                }
+            }
+        }
+
+        impl<T: Clone> From<&[T]> for $name<T> {
+            fn from(slice: &[T]) -> Self {
+                $crate::FrontVec::from(slice).into()
+            }
+        }
+
+        impl<T: Clone, const N: usize> From<&[T; N]> for $name<T> {
+            fn from(array: &[T; N]) -> Self {
+                array.as_ref().into()
+            }
+        }
+
+        impl<T: PartialEq> PartialEq for $name<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_ref() == other.as_ref()
+            }
+        }
+
+        impl<T: Eq> Eq for $name<T> {}
+
+        impl<T> Default for $name<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_shared_front_vec;
@@ -0,0 +1,51 @@
+use alloc::rc::Rc;
+use core::cell::Cell;
+
+use super::shared::{impl_shared_front_vec, Claims, Shared};
+
+/// A persistent, immutable cons-list backed by a shared [`FrontVec`]-style
+/// buffer.
+///
+/// Cloning is O(1) and shares the buffer, just like sharing the tail of a
+/// cons-list. Each list is a view of the last `len` elements of the buffer.
+///
+/// # Memory Layout:
+/// ```ignore
+///                    [?, ?, ?, x, a, b, c]
+/// xs = [x, a, b, c]            ^^^^^^^^^^
+/// ys = [a, b, c]                  ^^^^^^^
+/// ```
+/// Here `xs` owns the next free slot (its length equals the number of
+/// *claimed* slots), so `xs.push_front(w)` writes in place. `ys` does not, so
+/// `ys.push_front(y)` copies `[a, b, c]` into a fresh buffer first.
+///
+/// [`FrontVec`]: crate::FrontVec
+pub struct SharedFrontVec<T> {
+    shared: Rc<Shared<T, Cell<usize>>>,
+    len: usize,
+}
+
+impl Claims for Cell<usize> {
+    fn new(claimed: usize) -> Self {
+        Cell::new(claimed)
+    }
+
+    fn current(&self) -> usize {
+        self.get()
+    }
+
+    fn current_mut(&mut self) -> &mut usize {
+        self.get_mut()
+    }
+
+    fn try_advance(&self, len: usize) -> bool {
+        if self.get() != len {
+            return false;
+        }
+
+        self.set(len + 1);
+        true
+    }
+}
+
+impl_shared_front_vec!(SharedFrontVec, Rc);
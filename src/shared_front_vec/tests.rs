@@ -0,0 +1,88 @@
+use crate::{FrontVec, SharedFrontVec};
+use std::rc::Rc;
+
+#[test]
+fn cons_list_api() {
+    let nil = SharedFrontVec::new();
+    let xs = SharedFrontVec::cons(3, &nil);
+    let xs = SharedFrontVec::cons(2, &xs);
+    let xs = SharedFrontVec::cons(1, &xs);
+    assert_eq!(xs.as_ref(), &[1, 2, 3]);
+    assert_eq!(xs.head(), Some(&1));
+
+    let tail = xs.tail().unwrap();
+    assert_eq!(tail.as_ref(), &[2, 3]);
+    assert!(tail.shares_buffer(&xs));
+    assert!(nil.tail().is_none());
+}
+
+#[test]
+fn push_in_place_when_owning_next_slot() {
+    let mut xs = SharedFrontVec::with_capacity(4);
+    xs.push_front(3);
+    let ys = xs.clone();
+    assert!(xs.shares_buffer(&ys));
+
+    xs.push_front(2);
+    assert!(xs.shares_buffer(&ys));
+    assert_eq!(xs.as_ref(), &[2, 3]);
+    assert_eq!(ys.as_ref(), &[3]);
+    assert!(std::ptr::eq(&xs[1], &ys[0]));
+}
+
+#[test]
+fn copy_on_write_when_slot_is_taken() {
+    let mut xs = SharedFrontVec::from(&[3]);
+    let mut ys = xs.clone();
+
+    xs.push_front(2);
+    ys.push_front(9);
+    assert!(!xs.shares_buffer(&ys));
+    assert_eq!(xs.as_ref(), &[2, 3]);
+    assert_eq!(ys.as_ref(), &[9, 3]);
+
+    // `ys` now owns its buffer's next slot.
+    let zs = ys.clone();
+    ys.push_front(8);
+    assert!(ys.shares_buffer(&zs));
+}
+
+#[test]
+fn unique_owner_reuses_slots() {
+    let mut xs = SharedFrontVec::from(FrontVec::from(vec![1, 2, 3]));
+    xs = xs.tail().unwrap();
+    let cap = xs.capacity();
+    xs.push_front(100);
+    assert_eq!(xs.as_ref(), &[100, 2, 3]);
+    assert_eq!(xs.capacity(), cap);
+}
+
+#[test]
+fn grows_when_full() {
+    let mut xs = SharedFrontVec::new();
+    for i in (0..100).rev() {
+        xs.push_front(i);
+    }
+    assert!(xs.iter().copied().eq(0..100));
+}
+
+#[test]
+fn drops_every_element() {
+    let rc = Rc::new(());
+    {
+        let mut xs = SharedFrontVec::new();
+        xs.push_front(rc.clone());
+        let mut ys = xs.clone();
+        xs.push_front(rc.clone());
+        ys.push_front(rc.clone());
+        let zs = ys.tail().unwrap();
+        drop(ys);
+        assert_eq!(zs.len(), 1);
+        assert!(Rc::strong_count(&rc) > 1);
+
+        let mut unique = SharedFrontVec::from(&[rc.clone(), rc.clone()]);
+        unique.truncate(1);
+        unique.push_front(rc.clone());
+    }
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
@@ -0,0 +1,113 @@
+//! Global allocation counters and a growth hook, for tuning headroom sizes.
+//!
+//! Enabled by the `stats` feature. The counters are process-wide and cover
+//! every buffer allocated by this crate. They're updated with relaxed atomics,
+//! so a [`snapshot`] taken while other threads are growing buffers may be
+//! slightly inconsistent.
+//!
+//! The counters are `usize`s rather than `u64`s, so that they work on targets
+//! without 64-bit atomics. They wrap around on overflow, which on 32-bit
+//! targets can happen for `bytes_copied` in long-running processes; compare
+//! snapshots with `wrapping_sub` there.
+//!
+//! # Example
+//!
+//! ```
+//! # use front_vec::{stats, FrontVec};
+//! fn log_growth(event: &stats::GrowthEvent) {
+//!     println!("grew to {} bytes", event.new_capacity_bytes);
+//! }
+//! stats::set_growth_hook(Some(log_growth));
+//!
+//! let before = stats::snapshot();
+//! let mut v = FrontVec::new();
+//! for i in 0..100u32 {
+//!     v.push_front(i);
+//! }
+//! let after = stats::snapshot();
+//! assert!(after.growths - before.growths >= 5);
+//! # stats::set_growth_hook(None);
+//! ```
+
+use core::{
+    mem, ptr,
+    sync::atomic::{AtomicPtr, AtomicUsize, Ordering::Relaxed},
+};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static GROWTHS: AtomicUsize = AtomicUsize::new(0);
+static BYTES_COPIED: AtomicUsize = AtomicUsize::new(0);
+static PEAK_CAPACITY_BYTES: AtomicUsize = AtomicUsize::new(0);
+/// A `fn(&GrowthEvent)`, or null.
+static GROWTH_HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// A snapshot of the global counters. See [`snapshot`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of buffers allocated, including those allocated to grow
+    /// (except with `realloc`).
+    pub allocations: usize,
+    /// The number of times a buffer was replaced by (or `realloc`ed into) a
+    /// bigger one.
+    pub growths: usize,
+    /// The number of bytes of elements copied while growing.
+    pub bytes_copied: usize,
+    /// The size in bytes of the biggest buffer allocated.
+    pub peak_capacity_bytes: usize,
+}
+
+/// Describes a single growth, passed to the hook set by [`set_growth_hook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GrowthEvent {
+    pub old_capacity_bytes: usize,
+    pub new_capacity_bytes: usize,
+    /// The number of bytes of elements copied into the new buffer.
+    pub bytes_copied: usize,
+}
+
+pub fn snapshot() -> Stats {
+    Stats {
+        allocations: ALLOCATIONS.load(Relaxed),
+        growths: GROWTHS.load(Relaxed),
+        bytes_copied: BYTES_COPIED.load(Relaxed),
+        peak_capacity_bytes: PEAK_CAPACITY_BYTES.load(Relaxed),
+    }
+}
+
+/// Resets all counters to zero. The growth hook is left alone.
+pub fn reset() {
+    ALLOCATIONS.store(0, Relaxed);
+    GROWTHS.store(0, Relaxed);
+    BYTES_COPIED.store(0, Relaxed);
+    PEAK_CAPACITY_BYTES.store(0, Relaxed);
+}
+
+/// Sets (or with `None`, clears) the function called after every growth. It
+/// runs on the thread that grew the buffer, so it should be quick.
+pub fn set_growth_hook(hook: Option<fn(&GrowthEvent)>) {
+    let ptr = match hook {
+        Some(hook) => hook as *mut (),
+        None => ptr::null_mut(),
+    };
+    GROWTH_HOOK.store(ptr, Relaxed);
+}
+
+pub(crate) fn record_allocation(capacity_bytes: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    PEAK_CAPACITY_BYTES.fetch_max(capacity_bytes, Relaxed);
+}
+
+pub(crate) fn record_growth(event: GrowthEvent) {
+    GROWTHS.fetch_add(1, Relaxed);
+    BYTES_COPIED.fetch_add(event.bytes_copied, Relaxed);
+    // Growing with `realloc` doesn't go through `record_allocation`.
+    PEAK_CAPACITY_BYTES.fetch_max(event.new_capacity_bytes, Relaxed);
+
+    let hook = GROWTH_HOOK.load(Relaxed);
+    if !hook.is_null() {
+        // SAFETY: Only `set_growth_hook` stores non-null pointers, and those
+        // are always `fn(&GrowthEvent)`s.
+        let hook = unsafe { mem::transmute::<*mut (), fn(&GrowthEvent)>(hook) };
+        hook(&event);
+    }
+}